@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use pesel::pesel::PESEL as PESEL;
+use pesel::pesel_fast::{self, RECORD_LENGTH};
+use std::str::FromStr;
+
+/// Number of records validated in every iteration
+const RECORDS: usize = 10_000;
+
+/// Buffer of newline separated records for consecutive birth dates, every tenth of them failing the checksum check
+fn records() -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(RECORDS * (RECORD_LENGTH + 1));
+    for index in 0..RECORDS {
+        let number = format!("4405{:02}{:04}", 1 + index / 1000 % 28, index % 1000);
+        let sum: u32 = number.bytes().zip([9, 7, 3, 1, 9, 7, 3, 1, 9, 7].iter()).map(|(digit, weight)| (digit - b'0') as u32 * weight).sum();
+        let checksum = match index % 10 {
+            0 => (sum + 1) % 10,
+            _ => sum % 10,
+        };
+        buffer.extend_from_slice(number.as_bytes());
+        buffer.push(b'0' + checksum as u8);
+        buffer.push(b'\n');
+    }
+    buffer
+}
+
+fn validation(c: &mut Criterion) {
+    let buffer = records();
+    let lines: Vec<&str> = std::str::from_utf8(&buffer).unwrap().lines().collect();
+
+    let mut group = c.benchmark_group("validation");
+    group.throughput(Throughput::Elements(RECORDS as u64));
+    group.bench_function("PESEL::from_str", |b| b.iter(|| {
+        lines.iter().filter(|line| PESEL::from_str(black_box(line)).map(|pesel| pesel.is_valid()).unwrap_or(false)).count()
+    }));
+    group.bench_function("pesel_fast::validate_fixed_width_scalar", |b| b.iter(|| {
+        pesel_fast::validate_fixed_width_scalar(black_box(&buffer), RECORD_LENGTH + 1).filter(|result| result.is_ok()).count()
+    }));
+    group.bench_function("pesel_fast::validate_fixed_width", |b| b.iter(|| {
+        pesel_fast::validate_fixed_width(black_box(&buffer), RECORD_LENGTH + 1).filter(|result| result.is_ok()).count()
+    }));
+    group.finish();
+}
+
+criterion_group!(benches, validation);
+criterion_main!(benches);
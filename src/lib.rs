@@ -1,3 +1,41 @@
-#[macro_use]
+//! PESEL validation & generation library.
+//!
+//! Parsing, checksum and date validation (including fast validation of raw byte buffers, `pesel_fast`) work without the standard library (`no_std`, a global allocator is required).
+//! The rest is enabled with cargo features (all enabled by default):
+//! - `std` - reading PESELs from files and readers (`pesel_batch`), `std::error::Error` implementation
+//! - `rand` - generating PESELs (`PESEL::new` and friends, `pesel_generator`, `pesel_population`)
+//! - `chrono` - `chrono` date of birth accessors, age calculation, enumeration and correction suggestions
+//!
+//! Optional features: `serde` (`pesel_serde`), `time` (`time::Date` date of birth accessor), `rayon` (parallel validation, `pesel_parallel`) and `futures`
+//! (async streams, `pesel_stream`).
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 pub mod pesel;
 pub mod pesel_parsing_error;
+pub mod pesel_validator;
+pub mod pesel_exceptions;
+pub mod pesel_normalizer;
+pub mod pesel_correction;
+pub mod pesel_compact;
+pub mod pesel_fast;
+pub mod pesel_iter;
+pub mod pesel_components;
+pub mod pesel_explain;
+#[cfg(feature = "std")]
+pub mod pesel_batch;
+#[cfg(feature = "serde")]
+pub mod pesel_serde;
+#[cfg(feature = "rayon")]
+pub mod pesel_parallel;
+#[cfg(feature = "futures")]
+pub mod pesel_stream;
+#[cfg(feature = "chrono")]
+pub mod pesel_enumeration;
+#[cfg(all(feature = "rand", feature = "chrono"))]
+pub mod pesel_generator;
+#[cfg(all(feature = "rand", feature = "chrono"))]
+pub mod pesel_population;
+#[cfg(feature = "chrono")]
+pub mod pesel_age;
@@ -1,18 +1,456 @@
+use std::io::BufRead;
+use std::process;
 use std::str::FromStr;
+use rand::{RngCore, SeedableRng};
+use rand::rngs::StdRng;
 use pesel::pesel::{PESEL as PESEL, PeselGender};
+use pesel::pesel_batch::{PeselBatchRecord, PeselBatchSummary};
+use pesel::pesel_explain::PeselExplanation;
+use pesel::pesel_parsing_error::{PeselError, PeselErrorKind};
+
+const USAGE: &str = "\
+pesel - PESEL validation & generation tool
+
+USAGE:
+    pesel [--format text|json|csv] <COMMAND> [ARGS]
+
+COMMANDS:
+    validate [PESEL...]                     validate PESEL numbers (read from stdin, one per line, if none given)
+    decode [PESEL...]                       show date of birth, gender and checksum status of PESEL numbers
+    explain [PESEL...]                      show checksum calculation and date of birth decoding step by step
+    generate <YYYY-MM-DD> <male|female>     generate random PESEL number(s) for given birth date and gender
+             [--count N] [--seed N]
+    batch [FILE]                            validate a file (or stdin), one PESEL per line, and print a summary to stderr
+
+OPTIONS:
+    --format text|json|csv                  output format (default: text); json output is one JSON object per line
+    -h, --help                              print this message
+
+EXIT CODES:
+    0   success (all PESEL numbers valid)
+    1   checksum check failed
+    2   invalid command line
+    3   PESEL has wrong length
+    4   PESEL contains characters other than digits
+    5   invalid birth date
+    6   birth date out of range
+    7   I/O error
+    8   serial number out of range
+    9   gender digit does not match gender
+    10  not enough distinct PESEL numbers available
+    11  invalid population weights
+    12  invalid ratio (i.e. fraction of women)";
+
+const EXIT_OK: i32 = 0;
+const EXIT_INVALID_CHECKSUM: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+const EXIT_IO_ERROR: i32 = 7;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("unknown output format: {}", s)),
+        }
+    }
+}
 
 fn main() {
-    let pesel_number ="44051401458";
-    let pesel = PESEL::from_str(pesel_number);
-    match pesel {
-        Ok(t) => println!("{}", t),
-        _ => panic!("invalid PESEL provided")
-    }
-
-    println!("--- PESEL generation ----");
-    let generated_pesel = PESEL::new(1980, 05, 26, PeselGender::Male );
-    match generated_pesel {
-        Ok(pesel_number) => println!("generated_pesel: {}", pesel_number),
-        _ => println!("unable to create PESEL for specified date")
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    process::exit(match run(args) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            EXIT_USAGE
+        }
+    });
+}
+
+/// Parses command line and dispatches to the requested subcommand. Returns process exit code, or error message for invalid command line.
+fn run(args: Vec<String>) -> Result<i32, String> {
+    let mut format = OutputFormat::Text;
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            println!("{}", USAGE);
+            return Ok(EXIT_OK);
+        } else if arg == "--format" {
+            format = args.next().ok_or("--format requires a value")?.parse()?;
+        } else if let Some(value) = arg.strip_prefix("--format=") {
+            format = value.parse()?;
+        } else {
+            rest.push(arg);
+        }
+    }
+
+    if rest.is_empty() {
+        return Err("no command given".to_string());
+    }
+    let command = rest.remove(0);
+    match command.as_str() {
+        "validate" => Ok(validate(&rest, format)),
+        "decode" => Ok(decode(&rest, format)),
+        "explain" => Ok(explain(&rest, format)),
+        "generate" => generate(&rest, format),
+        "batch" => batch(&rest, format),
+        _ => Err(format!("unknown command: {}", command)),
+    }
+}
+
+/// Maps every kind of `PeselError` to a distinct process exit code
+fn exit_code(error: PeselError) -> i32 {
+    match error.kind() {
+        PeselErrorKind::InvalidChecksum => EXIT_INVALID_CHECKSUM,
+        PeselErrorKind::SizeError => 3,
+        PeselErrorKind::BadFormat => 4,
+        PeselErrorKind::InvalidDoB => 5,
+        PeselErrorKind::DoBOutOfRange => 6,
+        PeselErrorKind::InvalidSerial => 8,
+        PeselErrorKind::GenderMismatch => 9,
+        PeselErrorKind::NotEnoughNumbers => 10,
+        PeselErrorKind::InvalidWeights => 11,
+        PeselErrorKind::InvalidRatio => 12,
+    }
+}
+
+/// Exit code for a single parsing result
+fn result_exit_code(result: &Result<PESEL, PeselError>) -> i32 {
+    match result {
+        Ok(pesel) if pesel.is_valid() => EXIT_OK,
+        Ok(_) => EXIT_INVALID_CHECKSUM,
+        Err(e) => exit_code(*e),
+    }
+}
+
+/// Keeps the first non-zero exit code
+fn merge_exit_code(current: i32, next: i32) -> i32 {
+    if current == EXIT_OK { next } else { current }
+}
+
+/// Calls `f` for every PESEL number given as argument or, if there are none, for every non-blank line read from stdin
+fn for_each_input<F: FnMut(&str, Result<PESEL, PeselError>)>(inputs: &[String], mut f: F) -> Result<(), std::io::Error> {
+    if !inputs.is_empty() {
+        for input in inputs {
+            f(input, PESEL::from_str(input));
+        }
+        return Ok(());
+    }
+    let stdin = std::io::stdin();
+    for record in PESEL::from_reader(stdin.lock()) {
+        let record = record?;
+        f(&record.input, record.result);
+    }
+    Ok(())
+}
+
+fn validate(inputs: &[String], format: OutputFormat) -> i32 {
+    let mut code = EXIT_OK;
+    if format == OutputFormat::Csv {
+        println!("pesel,status,error");
+    }
+    let result = for_each_input(inputs, |input, result| {
+        print_validation(None, input, &result, format);
+        code = merge_exit_code(code, result_exit_code(&result));
+    });
+    match result {
+        Ok(()) => code,
+        Err(e) => io_error(e),
+    }
+}
+
+fn decode(inputs: &[String], format: OutputFormat) -> i32 {
+    let mut code = EXIT_OK;
+    if format == OutputFormat::Csv {
+        println!("pesel,date_of_birth,gender,valid,error");
+    }
+    let result = for_each_input(inputs, |input, result| {
+        code = merge_exit_code(code, result_exit_code(&result));
+        match (result, format) {
+            (Ok(pesel), OutputFormat::Text) => println!("{}\n", pesel),
+            (Ok(pesel), OutputFormat::Json) => println!("{{\"pesel\":{},\"date_of_birth\":\"{}\",\"gender\":\"{}\",\"valid\":{}}}",
+                json_string(&pesel.pesel_number()), pesel.date_of_birth().format("%Y-%m-%d"), pesel.gender(), pesel.is_valid()),
+            (Ok(pesel), OutputFormat::Csv) => println!("{},{},{},{},",
+                pesel.pesel_number(), pesel.date_of_birth().format("%Y-%m-%d"), pesel.gender(), pesel.is_valid()),
+            (Err(e), OutputFormat::Text) => println!("{}: {}\n", input, e),
+            (Err(e), OutputFormat::Json) => println!("{{\"pesel\":{},\"error\":\"{}\",\"message\":{}}}",
+                json_string(input), e.pesel_error_to_tag(), json_string(&e.to_string())),
+            (Err(e), OutputFormat::Csv) => println!("{},,,,{}", csv_field(input), e.pesel_error_to_tag()),
+        }
+    });
+    match result {
+        Ok(()) => code,
+        Err(e) => io_error(e),
+    }
+}
+
+fn explain(inputs: &[String], format: OutputFormat) -> i32 {
+    let mut code = EXIT_OK;
+    if format == OutputFormat::Csv {
+        println!("pesel,sum,expected_check_digit,check_digit,valid,century,error");
+    }
+    let result = for_each_input(inputs, |input, result| {
+        code = merge_exit_code(code, result_exit_code(&result));
+        match (result.map(|pesel| pesel.explain()), format) {
+            (Ok(explanation), OutputFormat::Text) => println!("{}\n", explanation),
+            (Ok(explanation), OutputFormat::Json) => println!("{}", explanation_json(&explanation)),
+            (Ok(explanation), OutputFormat::Csv) => println!("{},{},{},{},{},{},", explanation.pesel, explanation.sum,
+                explanation.expected_check_digit, explanation.check_digit, explanation.is_valid, explanation.century.century),
+            (Err(e), OutputFormat::Text) => println!("{}: {}\n", input, e),
+            (Err(e), OutputFormat::Json) => println!("{{\"pesel\":{},\"error\":\"{}\",\"message\":{}}}",
+                json_string(input), e.pesel_error_to_tag(), json_string(&e.to_string())),
+            (Err(e), OutputFormat::Csv) => println!("{},,,,,,{}", csv_field(input), e.pesel_error_to_tag()),
+        }
+    });
+    match result {
+        Ok(()) => code,
+        Err(e) => io_error(e),
+    }
+}
+
+fn generate(args: &[String], format: OutputFormat) -> Result<i32, String> {
+    let mut count: usize = 1;
+    let mut seed: Option<u64> = None;
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--count" {
+            let value = args.next().ok_or("--count requires a value")?;
+            count = value.parse().map_err(|_| format!("invalid count: {}", value))?;
+        } else if arg == "--seed" {
+            let value = args.next().ok_or("--seed requires a value")?;
+            seed = Some(value.parse().map_err(|_| format!("invalid seed: {}", value))?);
+        } else {
+            positional.push(arg.as_str());
+        }
+    }
+    let (date, gender) = match positional.as_slice() {
+        [date, gender] => (*date, *gender),
+        _ => return Err("generate requires birth date and gender".to_string()),
+    };
+    let (year, month, day) = parse_date(date).ok_or(format!("invalid date: {} (expected YYYY-MM-DD)", date))?;
+    let gender = match gender {
+        "male" | "m" => PeselGender::Male,
+        "female" | "f" => PeselGender::Female,
+        _ => return Err(format!("invalid gender: {} (expected male or female)", gender)),
+    };
+
+    if format == OutputFormat::Csv {
+        println!("pesel");
+    }
+    let mut rng: Box<dyn RngCore> = match seed {
+        Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+        None => Box::new(rand::thread_rng()),
+    };
+    for _ in 0..count {
+        match PESEL::new_with_rng(year, month, day, gender, &mut rng) {
+            Ok(pesel) => match format {
+                OutputFormat::Text | OutputFormat::Csv => println!("{}", pesel.pesel_number()),
+                OutputFormat::Json => println!("{{\"pesel\":{}}}", json_string(&pesel.pesel_number())),
+            },
+            Err(e) => {
+                eprintln!("unable to generate PESEL: {}", e);
+                return Ok(exit_code(e));
+            }
+        }
+    }
+    Ok(EXIT_OK)
+}
+
+fn batch(args: &[String], format: OutputFormat) -> Result<i32, String> {
+    let reader: Box<dyn BufRead> = match args {
+        [] => Box::new(std::io::BufReader::new(std::io::stdin())),
+        [path] if path == "-" => Box::new(std::io::BufReader::new(std::io::stdin())),
+        [path] => match std::fs::File::open(path) {
+            Ok(file) => Box::new(std::io::BufReader::new(file)),
+            Err(e) => return Ok(io_error(e)),
+        },
+        _ => return Err("batch accepts at most one file".to_string()),
+    };
+    Ok(validate_lines(reader, format))
+}
+
+/// Validates every line read from `reader`. Summary is printed even if reading fails half way through.
+fn validate_lines<R: BufRead>(reader: R, format: OutputFormat) -> i32 {
+    let mut code = EXIT_OK;
+    let mut summary = PeselBatchSummary::default();
+    if format == OutputFormat::Csv {
+        println!("line,pesel,status,error");
+    }
+    for record in PESEL::from_reader(reader) {
+        let record: PeselBatchRecord = match record {
+            Ok(record) => record,
+            Err(e) => {
+                print_summary(&summary);
+                return io_error(e);
+            },
+        };
+        print_validation(Some(record.line_number), &record.input, &record.result, format);
+        code = merge_exit_code(code, result_exit_code(&record.result));
+        summary.add(&record);
+    }
+    print_summary(&summary);
+    code
+}
+
+/// Prints outcome of validation of a single PESEL number, optionally prefixed with line number
+fn print_validation(line: Option<usize>, input: &str, result: &Result<PESEL, PeselError>, format: OutputFormat) {
+    let (status, error) = match result {
+        Ok(pesel) if pesel.is_valid() => ("valid", None),
+        Ok(_) => ("invalid_checksum", None),
+        Err(e) => ("error", Some(*e)),
+    };
+    match format {
+        OutputFormat::Text => {
+            let prefix = line.map(|l| format!("{}: ", l)).unwrap_or_default();
+            match error {
+                Some(e) => println!("{}{}: error: {}", prefix, input, e),
+                None => println!("{}{}: {}", prefix, input, status.replace('_', " ")),
+            }
+        },
+        OutputFormat::Json => {
+            let line = line.map(|l| format!("\"line\":{},", l)).unwrap_or_default();
+            let error = error.map(|e| format!(",\"error\":\"{}\",\"message\":{}", e.pesel_error_to_tag(), json_string(&e.to_string()))).unwrap_or_default();
+            println!("{{{}\"pesel\":{},\"status\":\"{}\"{}}}", line, json_string(input), status, error);
+        },
+        OutputFormat::Csv => {
+            let line = line.map(|l| format!("{},", l)).unwrap_or_default();
+            println!("{}{},{},{}", line, csv_field(input), status, error.map(|e| e.pesel_error_to_tag()).unwrap_or(""));
+        },
+    }
+}
+
+fn print_summary(summary: &PeselBatchSummary) {
+    eprintln!("total: {}", summary.total);
+    eprintln!("valid: {}", summary.valid);
+    eprintln!("invalid checksum: {}", summary.invalid_checksum);
+    for kind in &[PeselErrorKind::SizeError, PeselErrorKind::BadFormat, PeselErrorKind::InvalidDoB, PeselErrorKind::DoBOutOfRange] {
+        eprintln!("{}: {}", kind.pesel_error_to_tag(), summary.error_count(*kind));
+    }
+}
+
+fn io_error(e: std::io::Error) -> i32 {
+    eprintln!("I/O error: {}", e);
+    EXIT_IO_ERROR
+}
+
+/// Parses date in YYYY-MM-DD format (validity of the date itself is checked when PESEL is created)
+fn parse_date(date: &str) -> Option<(u16, u8, u8)> {
+    let mut parts = date.split('-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    match parts.next() {
+        None => Some((year, month, day)),
+        Some(_) => None,
+    }
+}
+
+/// Encodes string as JSON string literal (including quotes)
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Encodes explanation as JSON object (same layout as produced by `serde` feature)
+fn explanation_json(explanation: &PeselExplanation) -> String {
+    let steps: Vec<String> = explanation.steps.iter()
+        .map(|step| format!("{{\"index\":{},\"digit\":{},\"weight\":{},\"product\":{}}}", step.index, step.digit, step.weight, step.product))
+        .collect();
+    let century = &explanation.century;
+    format!("{{\"pesel\":{},\"steps\":[{}],\"sum\":{},\"expected_check_digit\":{},\"check_digit\":{},\"is_valid\":{},\
+        \"century\":{{\"encoded_year\":{},\"encoded_month\":{},\"month_offset\":{},\"century\":{},\"birth_year\":{},\"birth_month\":{},\"birth_day\":{}}}}}",
+        json_string(&explanation.pesel), steps.join(","), explanation.sum, explanation.expected_check_digit, explanation.check_digit, explanation.is_valid,
+        century.encoded_year, century.encoded_month, century.month_offset, century.century, century.birth_year, century.birth_month, century.birth_day)
+}
+
+/// Quotes CSV field if needed
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
+
+    #[test]
+    fn date_should_be_parsed_from_iso_format() {
+        assert_eq!(Some((1980, 5, 26)), parse_date("1980-05-26"));
+        assert_eq!(None, parse_date("1980-05"));
+        assert_eq!(None, parse_date("1980-05-26-01"));
+        assert_eq!(None, parse_date("26.05.1980"));
+    }
+
+    #[test]
+    fn every_error_kind_should_have_distinct_exit_code() {
+        let codes: Vec<i32> = [PeselError::SizeError { expected: 11, found: 0 }, PeselError::BadFormat { index: 0, character: 'a' },
+            PeselError::InvalidDoB { year: 1993, month: 2, day: 29 }, PeselError::DoBOutOfRange { year: 1799 },
+            PeselError::InvalidSerial, PeselError::GenderMismatch, PeselError::NotEnoughNumbers, PeselError::InvalidWeights,
+            PeselError::InvalidRatio]
+            .iter().map(|e| exit_code(*e)).collect();
+
+        for (i, code) in codes.iter().enumerate() {
+            assert!(![EXIT_OK, EXIT_INVALID_CHECKSUM, EXIT_USAGE, EXIT_IO_ERROR].contains(code));
+            assert!(!codes[i + 1..].contains(code));
+        }
+    }
+
+    #[test]
+    fn line_which_is_not_utf8_should_not_stop_batch() {
+        let input: &[u8] = b"44051401458\n4405\xB11401458\n44051401459\n";
+
+        assert_eq!(exit_code(PeselError::BadFormat { index: 4, character: '\u{FFFD}' }), validate_lines(input, OutputFormat::Text));
+        assert_eq!(EXIT_INVALID_CHECKSUM, validate_lines(&b"44051401458\n44051401459\n"[..], OutputFormat::Text));
+    }
+
+    #[test]
+    fn json_strings_should_be_escaped() {
+        assert_eq!("\"4405\\\"14\\\\01\"", json_string("4405\"14\\01"));
+        assert_eq!("\"\\u0001\"", json_string("\u{1}"));
+    }
+
+    #[test]
+    fn explanation_should_be_encoded_as_json() {
+        let json = explanation_json(&PESEL::from_str("44051401459").unwrap().explain());
+
+        assert!(json.starts_with("{\"pesel\":\"44051401459\",\"steps\":[{\"index\":0,\"digit\":4,\"weight\":9,\"product\":36},"));
+        assert!(json.contains("\"sum\":178,\"expected_check_digit\":8,\"check_digit\":9,\"is_valid\":false,\"century\":{\"encoded_year\":44,"));
+        assert!(json.ends_with("\"birth_day\":14}}"));
+        #[cfg(feature = "serde")]
+        assert_eq!(serde_json::to_string(&PESEL::from_str("44051401459").unwrap().explain()).unwrap(), json);
+    }
+
+    #[test]
+    fn csv_fields_should_be_quoted_when_needed() {
+        assert_eq!("44051401458", csv_field("44051401458"));
+        assert_eq!("\"4405,\"\"1\"", csv_field("4405,\"1"));
     }
 }
@@ -1,11 +1,18 @@
 use crate::pesel_parsing_error::PeselError;
-use std::str::FromStr;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::str::FromStr;
 
-use rand::Rng;
-use rand::prelude::ThreadRng;
+#[cfg(feature = "rand")]
+use rand::{Rng, SeedableRng};
+#[cfg(feature = "rand")]
+use rand::rngs::StdRng;
 
 const PESEL_LENGTH: usize = 11;
 
+/// Weights of the first ten digits used in checksum calculation (see `PESEL::is_valid`)
+pub(crate) const CHECKSUM_WEIGHTS: [u8; PESEL_LENGTH - 1] = [9, 7, 3, 1, 9, 7, 3, 1, 9, 7];
+
 /// Enum to represent Male/Female
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum PeselGender {
@@ -13,8 +20,8 @@ pub enum PeselGender {
     Female,
 }
 
-impl std::fmt::Display for PeselGender {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+impl core::fmt::Display for PeselGender {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         let gender_name = match *self {
             PeselGender::Female => "female",
             PeselGender::Male => "male",
@@ -56,28 +63,77 @@ impl PESEL {
     /// }
     /// ```
     /// Returned PESEL structure is valid (i.e. passes validation algorithm check - `new_pesel.is_valid` should always return `true`
+    #[cfg(feature = "rand")]
     pub fn new(year: u16, month: u8, day: u8, pesel_gender: PeselGender) -> Result<PESEL, PeselError> {
+        PESEL::new_with_rng(year, month, day, pesel_gender, &mut rand::thread_rng())
+    }
 
-        if ! PESEL::is_date_in_range(year as i32) {
-            return Err(PeselError::new(PeselError::DoBOutOfRange));
-        }
-        if ! PESEL::is_valid_date( year as i32, month as u32, day as u32) {
-            return Err(PeselError::new(PeselError::InvalidDoB));
-        }
+    /// Same as `PESEL::new`, but the random part of PESEL number (serial and gender digit) is drawn from provided random number generator.
+    /// Generator in a known state always yields the same PESEL for the same date of birth and gender.
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::{PESEL as PESEL, PeselGender};
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// let pesel = PESEL::new_with_rng(1981, 5, 29, PeselGender::Female, &mut rng).unwrap();
+    /// println!("generated PESEL: {}", pesel);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn new_with_rng<R: Rng + ?Sized>(year: u16, month: u8, day: u8, pesel_gender: PeselGender, rng: &mut R) -> Result<PESEL, PeselError> {
+        PESEL::check_date(year, month, day)?;
 
-        let pesel_year = year % 100;
-        let pesel_month = month + PESEL::calc_month_century_offset(year);
+        let (random1, random2, random3) = PESEL::generate_random_values(rng);
 
-        let mut rng = rand::thread_rng();
-        let (random1, random2, random3) = PESEL::generate_random_values(&mut rng);
+        let gender = PESEL::generate_gender_digit(pesel_gender, rng);
 
-        let gender = PESEL::generate_gender_digit(pesel_gender, &mut rng);
+        PESEL::build(year, month, day, random1 as u16 * 100 + random2 as u16 * 10 + random3 as u16, gender)
+    }
 
-        let pesel_string =  format!("{:02}{:02}{:02}{:1}{:1}{:1}{:1}", pesel_year, pesel_month, day, random1, random2, random3, gender);
+    /// Creates PESEL from fully specified components:
+    /// - birth date
+    /// - 3-digit serial number (digits 7-9 of PESEL, 0..=999)
+    /// - gender digit (digit 10 of PESEL, odd for men, even for women)
+    /// - biological gender, which has to agree with gender digit
+    ///
+    /// Checksum is calculated, so returned PESEL is always valid. Errors are returned when date of birth is invalid or out of range, serial or gender digit
+    /// are out of range (`PeselError::InvalidSerial`) or gender digit does not match biological gender (`PeselError::GenderMismatch`).
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::{PESEL as PESEL, PeselGender};
+    ///
+    /// let pesel = PESEL::with_serial(1944, 5, 14, 14, 5, PeselGender::Male).unwrap();
+    /// assert_eq!("44051401458", pesel.pesel_number());
+    /// ```
+    pub fn with_serial(year: u16, month: u8, day: u8, serial: u16, gender_digit: u8, pesel_gender: PeselGender) -> Result<PESEL, PeselError> {
+        if serial > 999 || gender_digit > 9 {
+            return Err(PeselError::InvalidSerial);
+        }
+        if PESEL::gender_from_digit(gender_digit) != pesel_gender {
+            return Err(PeselError::GenderMismatch);
+        }
+        PESEL::check_date(year, month, day)?;
 
-        let checksum = PESEL::calc_checksum_from_pesel_string(&pesel_string);
+        PESEL::build(year, month, day, serial, gender_digit)
+    }
 
-        PESEL::from_str(format!("{}{:1}", &pesel_string, checksum).as_str())
+    /// Same as `PESEL::with_serial`, but serial and gender digit are given together as 4-digit ordinal number (digits 7-10 of PESEL, 0..=9999)
+    pub fn with_ordinal(year: u16, month: u8, day: u8, ordinal: u16, pesel_gender: PeselGender) -> Result<PESEL, PeselError> {
+        if ordinal > 9999 {
+            return Err(PeselError::InvalidSerial);
+        }
+        PESEL::with_serial(year, month, day, ordinal / 10, (ordinal % 10) as u8, pesel_gender)
+    }
+
+    /// Same as `PESEL::new`, but reproducible: the same seed, date of birth and gender always yield the same PESEL number.
+    /// Handy for generating test fixtures.
+    ///
+    /// Note: the sequence is stable for a given version of `rand` crate, but may change when `rand` is upgraded.
+    #[cfg(feature = "rand")]
+    pub fn new_with_seed(year: u16, month: u8, day: u8, pesel_gender: PeselGender, seed: u64) -> Result<PESEL, PeselError> {
+        PESEL::new_with_rng(year, month, day, pesel_gender, &mut StdRng::seed_from_u64(seed))
     }
 }
 
@@ -115,13 +171,9 @@ impl FromStr for PESEL {
     /// - not all characters inside string are digits
     /// - year of birth is out of range
     /// - birth date is incorrect (i.e. 30th of February, 31st of April...
+    #[allow(noop_method_call, clippy::needless_borrow)]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != PESEL_LENGTH {
-            return Err(PeselError::new(PeselError::SizeError));
-        }
-        if s.chars().any(|f| !f.is_ascii_digit()) {
-            return Err(PeselError::new(PeselError::BadFormat));
-        }
+        PESEL::check_format(s)?;
         // do not automatically validate PESEL struct and return Err if it doesn't pass validation check. Some PESEL numbers in Poland (still in use) have been generated incorrectly (probably database with exceptions is used).
         let checksum = s[10..11].parse::<u8>().unwrap();
         let gender  = s[9..10].parse::<u8>().unwrap();
@@ -130,21 +182,12 @@ impl FromStr for PESEL {
         let mob = s[2..4].parse::<u8>().unwrap();
         let dob = s[4..6].parse::<u8>().unwrap();
 
-        let real_year = PESEL::calc_year_from_pesel_encoded_month_and_year(yob, mob);
-        if ! PESEL::is_date_in_range(real_year) {
-            return Err(PeselError::new(PeselError::DoBOutOfRange));
-        }
-        if ! PESEL::is_valid_date( real_year, (mob % 20) as u32, dob as u32) {
-            return Err(PeselError::new(PeselError::InvalidDoB));
-        }
+        PESEL::check_encoded_date(yob, mob, dob)?;
 
         let calculated_checksum = PESEL::calc_checksum_from_pesel_string(&s);
         let pesel_is_valid = calculated_checksum == checksum;
 
-        let real_gender = match gender %2 == 0 {
-            true => PeselGender::Female,
-            false => PeselGender::Male,
-        };
+        let real_gender = PESEL::gender_from_digit(gender);
 
         Ok(PESEL{
             raw: s.clone().to_string(),
@@ -158,34 +201,125 @@ impl FromStr for PESEL {
     }
 }
 
-impl std::fmt::Display for PESEL {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+impl core::fmt::Display for PESEL {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         write!(f, "PESEL: {}\n\
-        date of birth: {}\n\
+        date of birth: {:04}-{:02}-{:02}\n\
         gender: {}\n\
-        valid: {}", self.raw, self.date_of_birth(), self.gender_name(), self.is_valid())
+        valid: {}", self.raw, self.birth_year(), self.birth_month(), self.birth_day(), self.gender_name(), self.is_valid())
     }
 }
 
 impl PESEL {
     /// Utility function - checks if date is within PESEL system range
-    fn is_date_in_range(year: i32) -> bool {
+    pub(crate) const fn is_date_in_range(year: i32) -> bool {
         match year < 1800 || year > 2299 {
             true => false,
             false => true
         }
     }
 
-    /// Utility function - checks if date is valid
-    fn is_valid_date(year: i32, month: u32, day: u32) -> bool {
-        use chrono::prelude::*;
-        let date = Local.ymd_opt(year, month, day);
+    /// Utility function - checks if string consists of exactly 11 digits
+    pub(crate) const fn check_format(s: &str) -> Result<(), PeselError> {
+        let bytes = s.as_bytes();
+        if bytes.len() != PESEL_LENGTH {
+            return Err(PeselError::SizeError { expected: PESEL_LENGTH, found: bytes.len() });
+        }
+        let mut index = 0;
+        while index < PESEL_LENGTH {
+            if !bytes[index].is_ascii_digit() {
+                return Err(PeselError::BadFormat { index, character: PESEL::char_at(bytes, index) });
+            }
+            index += 1;
+        }
+        Ok(())
+    }
+
+    /// Utility function - decodes UTF-8 character starting at given byte index
+    const fn char_at(bytes: &[u8], index: usize) -> char {
+        let (length, mut code) = match bytes[index] {
+            first @ 0x00..=0x7F => (1, first as u32),
+            first @ 0xC0..=0xDF => (2, (first & 0x1F) as u32),
+            first @ 0xE0..=0xEF => (3, (first & 0x0F) as u32),
+            first => (4, (first & 0x07) as u32),
+        };
+        let mut next = 1;
+        while next < length && index + next < bytes.len() {
+            code = (code << 6) | (bytes[index + next] & 0x3F) as u32;
+            next += 1;
+        }
+        match char::from_u32(code) {
+            Some(character) => character,
+            None => char::REPLACEMENT_CHARACTER,
+        }
+    }
+
+    /// Utility function - checks if date is valid (in proleptic Gregorian calendar)
+    const fn is_valid_date(year: i32, month: u32, day: u32) -> bool {
+        month >= 1 && month <= 12 && day >= 1 && day <= PESEL::days_in_month(year, month)
+    }
+
+    /// Utility function - returns number of days in given month (1-12)
+    pub(crate) const fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if PESEL::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Utility function - checks if year is a leap year
+    const fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
 
-        date != chrono::offset::LocalResult::None
+    /// Utility function - checks if date of birth encoded in PESEL (two-digit year, month with century offset, day) is valid
+    pub(crate) const fn check_encoded_date(yob: u8, mob: u8, dob: u8) -> Result<(), PeselError> {
+        let real_year = PESEL::calc_year_from_pesel_encoded_month_and_year(yob, mob);
+        if ! PESEL::is_date_in_range(real_year) {
+            return Err(PeselError::DoBOutOfRange { year: real_year });
+        }
+        if ! PESEL::is_valid_date( real_year, (mob % 20) as u32, dob as u32) {
+            return Err(PeselError::InvalidDoB { year: real_year, month: mob % 20, day: dob });
+        }
+        Ok(())
+    }
+
+    /// Utility function - checks if date of birth is valid and can be represented in PESEL
+    fn check_date(year: u16, month: u8, day: u8) -> Result<(), PeselError> {
+        if ! PESEL::is_date_in_range(year as i32) {
+            return Err(PeselError::DoBOutOfRange { year: year as i32 });
+        }
+        if ! PESEL::is_valid_date( year as i32, month as u32, day as u32) {
+            return Err(PeselError::InvalidDoB { year: year as i32, month, day });
+        }
+        Ok(())
+    }
+
+    /// Utility function - builds PESEL from already validated date of birth, serial and gender digit (calculating checksum)
+    fn build(year: u16, month: u8, day: u8, serial: u16, gender_digit: u8) -> Result<PESEL, PeselError> {
+        let pesel_year = year % 100;
+        let pesel_month = month + PESEL::calc_month_century_offset(year);
+
+        let pesel_string =  format!("{:02}{:02}{:02}{:03}{:1}", pesel_year, pesel_month, day, serial, gender_digit);
+
+        let checksum = PESEL::calc_checksum_from_pesel_string(&pesel_string);
+
+        PESEL::from_str(format!("{}{:1}", &pesel_string, checksum).as_str())
+    }
+
+    /// Utility function - returns biological gender coded by gender digit (odd - man, even - woman)
+    pub(crate) const fn gender_from_digit(gender_digit: u8) -> PeselGender {
+        match gender_digit % 2 {
+            0 => PeselGender::Female,
+            _ => PeselGender::Male,
+        }
     }
 
     /// Utility function - returns triple of random u8s (this is needed to fill some extra space being part of PESEL number
-    fn generate_random_values(rng: &mut ThreadRng) -> (u8, u8, u8) {
+    #[cfg(feature = "rand")]
+    fn generate_random_values<R: Rng + ?Sized>(rng: &mut R) -> (u8, u8, u8) {
         let random1 = rng.gen_range(0, 10) as u8;
         let random2 = rng.gen_range(0, 10) as u8;
         let random3 = rng.gen_range(0, 10) as u8;
@@ -193,6 +327,7 @@ impl PESEL {
     }
 
     /// Utility function - calculates offset to be added to month to code a century person has been born in
+    #[allow(clippy::let_and_return)]
     fn calc_month_century_offset(year: u16) -> u8 {
         let century = match year {
             1800..=1899 => 80,
@@ -205,7 +340,7 @@ impl PESEL {
         century
     }
 
-    fn calc_year_from_pesel_encoded_month_and_year(year: u8, month: u8) -> i32 {
+    pub(crate) const fn calc_year_from_pesel_encoded_month_and_year(year: u8, month: u8) -> i32 {
         year as i32 + match month {
             1..=12 => 1900,
             20..=32 => 2000,
@@ -219,7 +354,9 @@ impl PESEL {
     /// Utility function - returns digit corresponding to biological gender.
     /// Odd - represents man
     /// Even - represents woman
-    fn generate_gender_digit(pesel_gender: PeselGender, rng: &mut ThreadRng) -> u8 {
+    #[cfg(feature = "rand")]
+    #[allow(clippy::useless_vec, clippy::let_and_return)]
+    fn generate_gender_digit<R: Rng + ?Sized>(pesel_gender: PeselGender, rng: &mut R) -> u8 {
         let women = vec![0, 2, 4, 6, 8];
         let men = vec![1, 3, 5, 7, 9];
         let gender = match pesel_gender {
@@ -230,13 +367,22 @@ impl PESEL {
     }
 
     /// Utility function - calculates checksum directly from PESEL string
-    fn calc_checksum_from_pesel_string(pesel_string: &str) -> u8 {
+    pub(crate) fn calc_checksum_from_pesel_string(pesel_string: &str) -> u8 {
         let (a, b, c, d, e, f, g, h, i, j) = PESEL::extract_pesel_factors(pesel_string);
         PESEL::calc_checksum(a, b, c, d, e, f, g, h, i, j)
     }
 
+    /// Utility function - returns `PeselError::InvalidChecksum` if check digit does not match the one calculated from other digits
+    pub(crate) fn checksum_error(&self) -> Option<PeselError> {
+        match self.is_valid {
+            true => None,
+            false => Some(PeselError::InvalidChecksum { expected: PESEL::calc_checksum_from_pesel_string(&self.raw), found: self.checksum }),
+        }
+    }
+
     /// Utility function - calculates checksum when given all the factors as parameters
-    fn calc_checksum(a: u8, b: u8, c:u8, d:u8, e:u8, f:u8, g:u8, h:u8, i:u8, j:u8) -> u8 {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) const fn calc_checksum(a: u8, b: u8, c:u8, d:u8, e:u8, f:u8, g:u8, h:u8, i:u8, j:u8) -> u8 {
         let sum:u16 = 9 * a as u16 +
             7 * b as u16 +
             3 * c as u16 +
@@ -278,31 +424,55 @@ impl PESEL {
     ///
     /// Please note that some PESEL numbers that are in use in Poland are not properly generated, and thus this check may fail for a PESEL number that is officially used.
     /// Note: this value is precomputed
+    #[allow(clippy::doc_overindented_list_items)]
     pub fn is_valid(&self) -> bool {
         self.is_valid
     }
 
+    /// Returns check digit (the last digit of PESEL number), as found in the number - see `PESEL::is_valid`
+    pub fn check_digit(&self) -> u8 {
+        self.checksum
+    }
+
     /// Returns biological gender as PeselGender enum
     pub fn gender(&self) -> PeselGender {
         self.gender
     }
 
-    /// Returns date of birth as chrono::Date
-    pub fn date_of_birth(&self) -> chrono::Date<chrono::Local> {
-        let century:u16 = match self.mob {
-            0..=12 => 1900,
-            20..=32 => 2000,
-            40..=52 => 2100,
-            60..=72 => 2200,
-            80..=92 => 1800,
-            _ => panic!("invalid PESEL")
-        };
-        let year :u16 = self.yob as u16 + century;
-        let month = self.mob;
-        let day = self.dob;
+    /// Returns date of birth as `chrono::NaiveDate` (PESEL carries no information about time zone). Requires `chrono` feature.
+    #[cfg(feature = "chrono")]
+    pub fn date_of_birth(&self) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(self.birth_year() as i32, self.birth_month() as u32, self.birth_day() as u32)
+            .expect("date validated on construction")
+    }
 
-        use chrono::prelude::*;
-        Local.ymd_opt(year as i32, month as u32, day as u32).unwrap()
+    /// Returns date of birth as `time::Date` (requires `time` feature)
+    #[cfg(feature = "time")]
+    pub fn time_date_of_birth(&self) -> time::Date {
+        use core::convert::TryFrom;
+        let month = time::Month::try_from(self.birth_month()).expect("month has been validated when PESEL was created");
+        time::Date::from_calendar_date(self.birth_year() as i32, month, self.birth_day())
+            .expect("date has been validated when PESEL was created")
+    }
+
+    /// Returns year of birth (1800-2299), with century decoded from month
+    pub fn birth_year(&self) -> u16 {
+        PESEL::calc_year_from_pesel_encoded_month_and_year(self.yob, self.mob) as u16
+    }
+
+    /// Returns month of birth (1-12)
+    pub fn birth_month(&self) -> u8 {
+        self.mob % 20
+    }
+
+    /// Returns day of birth (1-31)
+    pub fn birth_day(&self) -> u8 {
+        self.dob
+    }
+
+    /// Returns first year of the century person has been born in, i.e. 1900 for 1944, 2000 for 2002 (as encoded in PESEL month)
+    pub fn birth_century(&self) -> u16 {
+        self.birth_year() - self.yob as u16
     }
 
     // Returns description of a biological gender of a person assigned PESEL number
@@ -313,8 +483,14 @@ impl PESEL {
     pub fn pesel_number(&self) -> String {
         self.raw.clone()
     }
+
+    /// Returns PESEL number as string slice (without allocating, unlike `pesel_number`)
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
 }
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod pesel_parsing_tests {
     use std::str::FromStr;
     use crate::pesel_parsing_error::PeselError;
@@ -324,7 +500,7 @@ mod pesel_parsing_tests {
         let pesel = super::PESEL::from_str("");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(super::PeselError::new(PeselError::SizeError), pesel.err().unwrap());
+        assert_eq!(PeselError::SizeError { expected: 11, found: 0 }, pesel.err().unwrap());
     }
 
     #[test]
@@ -332,7 +508,7 @@ mod pesel_parsing_tests {
         let pesel = super::PESEL::from_str("4405140145a");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::BadFormat), pesel.unwrap_err());
+        assert_eq!(PeselError::BadFormat { index: 10, character: 'a' }, pesel.unwrap_err());
     }
 
     #[test]
@@ -340,7 +516,7 @@ mod pesel_parsing_tests {
         let pesel = super::PESEL::from_str("800526199869");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(super::PeselError::new(PeselError::SizeError), pesel.err().unwrap());
+        assert_eq!(PeselError::SizeError { expected: 11, found: 12 }, pesel.err().unwrap());
     }
 
     #[test]
@@ -348,14 +524,16 @@ mod pesel_parsing_tests {
         let pesel = super::PESEL::from_str("8005261998");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(super::PeselError::new(PeselError::SizeError), pesel.err().unwrap());
+        assert_eq!(PeselError::SizeError { expected: 11, found: 10 }, pesel.err().unwrap());
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::zero_prefixed_literal, clippy::manual_ok_err)]
 mod pesel_base_tests {
     use std::str::FromStr;
     use crate::pesel::PeselGender;
+    use crate::pesel_parsing_error::PeselError;
 
     #[test]
     fn building_pesel_from_string() {
@@ -409,6 +587,7 @@ mod pesel_base_tests {
         assert_eq!(false, result.unwrap().is_valid());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn generated_pesel_should_be_valid() {
         let pesel = super::PESEL::new(1981, 06, 27, PeselGender::Female).unwrap();
@@ -416,6 +595,7 @@ mod pesel_base_tests {
         assert_eq!(true, pesel.is_valid());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn generated_pesel_should_have_proper_gender_set() {
         let pesel = super::PESEL::new(1981, 06, 27, PeselGender::Female).unwrap();
@@ -426,6 +606,7 @@ mod pesel_base_tests {
         assert_ne!(PeselGender::Male, pesel.gender());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn generated_pesel_should_have_proper_gender_set2() {
         let pesel = super::PESEL::new(1981, 06, 27, PeselGender::Male).unwrap();
@@ -436,6 +617,67 @@ mod pesel_base_tests {
         assert_ne!(PeselGender::Female, pesel.gender());
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn pesel_generated_from_the_same_seed_should_be_the_same() {
+        let first = super::PESEL::new_with_seed(1981, 6, 27, PeselGender::Female, 2019).unwrap();
+        let second = super::PESEL::new_with_seed(1981, 6, 27, PeselGender::Female, 2019).unwrap();
+
+        assert_eq!(first.pesel_number(), second.pesel_number());
+        assert!(first.is_valid());
+        assert_eq!(PeselGender::Female, first.gender());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn pesel_generated_from_seeded_rng_should_be_reproducible() {
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let first: Vec<String> = (0..10).map(|_| super::PESEL::new_with_rng(2005, 2, 28, PeselGender::Male, &mut rng).unwrap().pesel_number()).collect();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let second: Vec<String> = (0..10).map(|_| super::PESEL::new_with_rng(2005, 2, 28, PeselGender::Male, &mut rng).unwrap().pesel_number()).collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn pesel_should_be_built_from_serial() {
+        let pesel = super::PESEL::with_serial(1944, 5, 14, 14, 5, PeselGender::Male).unwrap();
+
+        assert_eq!("44051401458", pesel.pesel_number());
+        assert!(pesel.is_valid());
+    }
+
+    #[test]
+    fn pesel_should_be_built_from_ordinal() {
+        let pesel = super::PESEL::with_ordinal(2002, 7, 8, 362, PeselGender::Female).unwrap();
+
+        assert_eq!("02270803624", pesel.pesel_number());
+        assert!(pesel.is_valid());
+    }
+
+    #[test]
+    fn serial_contradicting_gender_should_result_in_error() {
+        let pesel = super::PESEL::with_serial(1944, 5, 14, 14, 5, PeselGender::Female);
+        assert_eq!(PeselError::GenderMismatch, pesel.unwrap_err());
+
+        let pesel = super::PESEL::with_ordinal(1944, 5, 14, 146, PeselGender::Male);
+        assert_eq!(PeselError::GenderMismatch, pesel.unwrap_err());
+    }
+
+    #[test]
+    fn serial_out_of_range_should_result_in_error() {
+        assert_eq!(PeselError::InvalidSerial, super::PESEL::with_serial(1944, 5, 14, 1000, 5, PeselGender::Male).unwrap_err());
+        assert_eq!(PeselError::InvalidSerial, super::PESEL::with_serial(1944, 5, 14, 14, 10, PeselGender::Female).unwrap_err());
+        assert_eq!(PeselError::InvalidSerial, super::PESEL::with_ordinal(1944, 5, 14, 10000, PeselGender::Female).unwrap_err());
+    }
+
+    #[test]
+    fn pesel_built_from_serial_should_validate_date() {
+        assert_eq!(PeselError::InvalidDoB { year: 1993, month: 2, day: 29 }, super::PESEL::with_serial(1993, 2, 29, 14, 5, PeselGender::Male).unwrap_err());
+        assert_eq!(PeselError::DoBOutOfRange { year: 2300 }, super::PESEL::with_ordinal(2300, 1, 1, 145, PeselGender::Male).unwrap_err());
+    }
+
     #[test]
     fn pesel_number_stored_should_be_accessible() {
         let input = "44051401468";
@@ -446,8 +688,10 @@ mod pesel_base_tests {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::zero_prefixed_literal)]
 mod pesel_date_tests {
     use std::str::FromStr;
+    #[cfg(feature = "rand")]
     use crate::pesel::PeselGender;
     use crate::pesel_parsing_error::PeselError;
 
@@ -456,7 +700,7 @@ mod pesel_date_tests {
         let pesel = super::PESEL::from_str("44951201458");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::DoBOutOfRange), pesel.unwrap_err());
+        assert_eq!(PeselError::DoBOutOfRange { year: 44 }, pesel.unwrap_err());
     }
 
     #[test]
@@ -464,15 +708,54 @@ mod pesel_date_tests {
         let pesel = super::PESEL::from_str("44053201458");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::InvalidDoB), pesel.unwrap_err());
+        assert_eq!(PeselError::InvalidDoB { year: 1944, month: 5, day: 32 }, pesel.unwrap_err());
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn birth_date_should_be_returned_as_yyyy_mm_dd() {
         let pesel = super::PESEL::from_str("44051401458").unwrap();
 
         assert_eq!("1944-05-14", pesel.date_of_birth().format("%Y-%m-%d").to_string());
     }
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn birth_date_after_1999_should_be_decoded() {
+        let pesel = super::PESEL::from_str("02270803624").unwrap();
+
+        assert_eq!("2002-07-08", pesel.date_of_birth().format("%Y-%m-%d").to_string());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn birth_dates_from_all_centuries_should_be_decoded() {
+        let cases = [
+            ("44851401453", 1844, 1800),
+            ("44051401458", 1944, 1900),
+            ("44251401454", 2044, 2000),
+            ("44451401450", 2144, 2100),
+            ("44651401456", 2244, 2200),
+        ];
+        for (input, year, century) in cases.iter() {
+            let pesel = super::PESEL::from_str(input).unwrap();
+
+            assert_eq!(chrono::NaiveDate::from_ymd_opt(*year as i32, 5, 14), Some(pesel.date_of_birth()));
+            assert_eq!(*year, pesel.birth_year());
+            assert_eq!(5, pesel.birth_month());
+            assert_eq!(14, pesel.birth_day());
+            assert_eq!(*century, pesel.birth_century());
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn birth_date_should_be_available_as_time_date() {
+        let pesel = super::PESEL::from_str("02270803624").unwrap();
+
+        assert_eq!(time::Date::from_calendar_date(2002, time::Month::July, 8).unwrap(), pesel.time_date_of_birth());
+    }
+
+    #[cfg(all(feature = "rand", feature = "chrono"))]
     #[test]
     fn generated_pesel_should_print_proper_birth_date() {
         let pesel = super::PESEL::new(1981, 06, 27, PeselGender::Female).unwrap();
@@ -480,6 +763,7 @@ mod pesel_date_tests {
         assert_eq!("1981-06-27", pesel.date_of_birth().format("%Y-%m-%d").to_string());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn check_for_add_with_overflow() {
         // This test is very specific. It makes sure, that generated pesel, containing many high values (digits) will not result in overflow when calculating checksum
@@ -492,13 +776,14 @@ mod pesel_date_tests {
         assert_eq!(true, pesel.is_valid());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn creating_pesel_from_invalid_date_should_result_in_error() {
         // 1993 for sure was not a leap year...
         let pesel = super::PESEL::new(1993, 02, 29, PeselGender::Female);
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::InvalidDoB), pesel.err().unwrap());
+        assert_eq!(PeselError::InvalidDoB { year: 1993, month: 2, day: 29 }, pesel.err().unwrap());
     }
 
     #[test]
@@ -507,15 +792,16 @@ mod pesel_date_tests {
         let pesel = super::PESEL::from_str("83022998790");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::InvalidDoB), pesel.err().unwrap());
+        assert_eq!(PeselError::InvalidDoB { year: 1983, month: 2, day: 29 }, pesel.err().unwrap());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn creating_pesel_with_32nd_day_of_month_should_result_in_error() {
         let pesel = super::PESEL::new(1982, 05, 32, PeselGender::Male);
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::InvalidDoB), pesel.err().unwrap());
+        assert_eq!(PeselError::InvalidDoB { year: 1982, month: 5, day: 32 }, pesel.err().unwrap());
     }
 
     #[test]
@@ -523,7 +809,7 @@ mod pesel_date_tests {
         let pesel = super::PESEL::from_str("97043289891");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::InvalidDoB), pesel.err().unwrap());
+        assert_eq!(PeselError::InvalidDoB { year: 1997, month: 4, day: 32 }, pesel.err().unwrap());
     }
 
     #[test]
@@ -531,7 +817,7 @@ mod pesel_date_tests {
         let pesel = super::PESEL::from_str("97043189891");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::InvalidDoB), pesel.err().unwrap());
+        assert_eq!(PeselError::InvalidDoB { year: 1997, month: 4, day: 31 }, pesel.err().unwrap());
     }
 
     #[test]
@@ -539,23 +825,25 @@ mod pesel_date_tests {
         let pesel = super::PESEL::from_str("80063144451");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::InvalidDoB), pesel.err().unwrap());
+        assert_eq!(PeselError::InvalidDoB { year: 1980, month: 6, day: 31 }, pesel.err().unwrap());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn creating_pesel_from_date_earlier_than_1800y_should_result_in_error() {
         let pesel = super::PESEL::new(1799, 02, 06, PeselGender::Female);
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::DoBOutOfRange), pesel.err().unwrap());
+        assert_eq!(PeselError::DoBOutOfRange { year: 1799 }, pesel.err().unwrap());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
     fn creating_pesel_from_date_after_2299y_should_result_in_error() {
         let pesel = super::PESEL::new(2300, 01, 01, PeselGender::Female);
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::DoBOutOfRange), pesel.err().unwrap());
+        assert_eq!(PeselError::DoBOutOfRange { year: 2300 }, pesel.err().unwrap());
     }
 
     #[test]
@@ -563,7 +851,7 @@ mod pesel_date_tests {
         let pesel = super::PESEL::from_str("99940656478");
 
         assert_eq!(true, pesel.is_err());
-        assert_eq!(PeselError::new(PeselError::DoBOutOfRange), pesel.err().unwrap());
+        assert_eq!(PeselError::DoBOutOfRange { year: 99 }, pesel.err().unwrap());
     }
 }
 
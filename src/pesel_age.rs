@@ -0,0 +1,166 @@
+use crate::pesel::PESEL;
+use chrono::{Datelike, NaiveDate};
+
+/// Age of majority in Poland
+pub const ADULT_AGE: u32 = 18;
+
+/// Age calculation. All methods taking a reference date return `None` (or `false`) if the date is earlier than date of birth.
+///
+/// People born on 29th of February celebrate birthday (and become one year older) on 28th of February in non-leap years,
+/// which follows the way periods are counted by Polish Civil Code (art. 112).
+impl PESEL {
+    /// Returns age in full years at given date
+    ///
+    /// Example:
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use chrono::NaiveDate;
+    /// use pesel::pesel::PESEL as PESEL;
+    ///
+    /// let pesel = PESEL::from_str("44051401458").unwrap();
+    /// assert_eq!(Some(74), pesel.age_at(NaiveDate::from_ymd_opt(2019, 5, 13).unwrap()));
+    /// assert_eq!(Some(75), pesel.age_at(NaiveDate::from_ymd_opt(2019, 5, 14).unwrap()));
+    /// ```
+    pub fn age_at(&self, date: NaiveDate) -> Option<u32> {
+        let birth = self.date_of_birth();
+        if date < birth {
+            return None;
+        }
+        let years = (date.year() - birth.year()) as u32;
+        match date < self.birthday_in(date.year()) {
+            true => Some(years - 1),
+            false => Some(years),
+        }
+    }
+
+    /// Returns age in full years as of today (local time)
+    pub fn age(&self) -> Option<u32> {
+        self.age_at(PESEL::today())
+    }
+
+    /// Returns age in full months at given date. When the day of birth does not exist in a month (i.e. 31st), last day of that month is used instead.
+    pub fn age_in_months_at(&self, date: NaiveDate) -> Option<u32> {
+        let birth = self.date_of_birth();
+        if date < birth {
+            return None;
+        }
+        let months = (date.year() - birth.year()) as u32 * 12 + date.month() - birth.month();
+        let anniversary_day = birth.day().min(PESEL::days_in_month(date.year(), date.month()));
+        match date.day() < anniversary_day {
+            true => Some(months - 1),
+            false => Some(months),
+        }
+    }
+
+    /// Returns age in days at given date (0 on the day of birth)
+    pub fn age_in_days_at(&self, date: NaiveDate) -> Option<u32> {
+        let birth = self.date_of_birth();
+        if date < birth {
+            return None;
+        }
+        Some((date - birth).num_days() as u32)
+    }
+
+    /// Checks if person is an adult (at least 18 years old) at given date
+    pub fn is_adult_at(&self, date: NaiveDate) -> bool {
+        matches!(self.age_at(date), Some(age) if age >= ADULT_AGE)
+    }
+
+    /// Checks if person is an adult (at least 18 years old) today (local time)
+    pub fn is_adult(&self) -> bool {
+        self.is_adult_at(PESEL::today())
+    }
+
+    /// Checks if person celebrates birthday at given date (day of birth itself does not count)
+    pub fn has_birthday_on(&self, date: NaiveDate) -> bool {
+        date > self.date_of_birth() && date == self.birthday_in(date.year())
+    }
+
+    /// Returns date of birthday in given year (28th of February in non-leap years for people born on 29th of February)
+    ///
+    /// Panics if `year` is out of range supported by `chrono::NaiveDate`.
+    pub fn birthday_in(&self, year: i32) -> NaiveDate {
+        let birth = self.date_of_birth();
+        let day = birth.day().min(PESEL::days_in_month(year, birth.month()));
+        NaiveDate::from_ymd_opt(year, birth.month(), day).expect("year out of range supported by chrono")
+    }
+
+    /// Utility function - returns today's date (local time)
+    pub(crate) fn today() -> NaiveDate {
+        chrono::Local::now().date_naive()
+    }
+}
+
+#[cfg(test)]
+mod pesel_age_tests {
+    use crate::pesel::PESEL;
+    use chrono::NaiveDate;
+    use std::str::FromStr;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn age_should_increase_on_birthday() {
+        let pesel = PESEL::from_str("44051401458").unwrap();
+
+        assert_eq!(Some(0), pesel.age_at(date(1944, 5, 14)));
+        assert_eq!(Some(74), pesel.age_at(date(2019, 5, 13)));
+        assert_eq!(Some(75), pesel.age_at(date(2019, 5, 14)));
+        assert_eq!(None, pesel.age_at(date(1944, 5, 13)));
+    }
+
+    #[test]
+    fn person_born_on_29th_of_february_should_get_older_on_28th_in_non_leap_years() {
+        let pesel = PESEL::with_ordinal(2000, 2, 29, 1, crate::pesel::PeselGender::Male).unwrap();
+
+        assert_eq!(Some(18), pesel.age_at(date(2018, 2, 28)));
+        assert_eq!(Some(17), pesel.age_at(date(2018, 2, 27)));
+        assert_eq!(Some(20), pesel.age_at(date(2020, 2, 29)));
+        assert_eq!(Some(19), pesel.age_at(date(2020, 2, 28)));
+        assert!(pesel.has_birthday_on(date(2019, 2, 28)));
+        assert!(!pesel.has_birthday_on(date(2019, 3, 1)));
+        assert!(pesel.has_birthday_on(date(2020, 2, 29)));
+        assert!(!pesel.has_birthday_on(date(2020, 2, 28)));
+        assert!(!pesel.has_birthday_on(date(2000, 2, 29)));
+    }
+
+    #[test]
+    fn adulthood_should_start_on_18th_birthday() {
+        let pesel = PESEL::from_str("02270803624").unwrap();
+
+        assert!(!pesel.is_adult_at(date(2020, 7, 7)));
+        assert!(pesel.is_adult_at(date(2020, 7, 8)));
+        assert!(!pesel.is_adult_at(date(1990, 1, 1)));
+    }
+
+    #[test]
+    fn age_in_months_should_count_full_months() {
+        let pesel = PESEL::with_ordinal(2019, 1, 31, 1, crate::pesel::PeselGender::Male).unwrap();
+
+        assert_eq!(Some(0), pesel.age_in_months_at(date(2019, 2, 27)));
+        assert_eq!(Some(1), pesel.age_in_months_at(date(2019, 2, 28)));
+        assert_eq!(Some(1), pesel.age_in_months_at(date(2019, 3, 30)));
+        assert_eq!(Some(2), pesel.age_in_months_at(date(2019, 3, 31)));
+        assert_eq!(Some(12), pesel.age_in_months_at(date(2020, 1, 31)));
+        assert_eq!(None, pesel.age_in_months_at(date(2019, 1, 30)));
+    }
+
+    #[test]
+    fn age_in_days_should_be_counted_from_birth() {
+        let pesel = PESEL::from_str("44051401458").unwrap();
+
+        assert_eq!(Some(0), pesel.age_in_days_at(date(1944, 5, 14)));
+        assert_eq!(Some(366), pesel.age_in_days_at(date(1945, 5, 15)));
+        assert_eq!(None, pesel.age_in_days_at(date(1944, 5, 1)));
+    }
+
+    #[test]
+    fn age_as_of_today_should_be_available() {
+        let pesel = PESEL::from_str("44051401458").unwrap();
+
+        assert!(pesel.age().unwrap() >= 75);
+        assert!(pesel.is_adult());
+    }
+}
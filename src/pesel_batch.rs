@@ -0,0 +1,249 @@
+use crate::pesel::PESEL;
+use crate::pesel_parsing_error::{PeselError, PeselErrorKind};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// Outcome of validating a single line of batch input
+#[derive(Debug)]
+pub struct PeselBatchRecord {
+    pub line_number:    usize,                          // line number in the input (counting from 1)
+    pub input:          String,                         // line content with surrounding whitespace trimmed
+    pub result:         Result<PESEL, PeselError>,      // parsed PESEL or the reason parsing failed
+}
+
+impl PeselBatchRecord {
+    /// Returns checksum status of the parsed PESEL (see `PESEL::is_valid`), or `None` if the line could not be parsed at all
+    pub fn checksum_status(&self) -> Option<bool> {
+        self.result.as_ref().ok().map(|pesel| pesel.is_valid())
+    }
+
+    /// Returns `true` only if the line has been parsed and the PESEL passes the checksum check
+    pub fn is_valid(&self) -> bool {
+        self.checksum_status() == Some(true)
+    }
+
+    /// Utility function - validates a single line of input (trimming surrounding whitespace), returns `None` for blank lines
+    pub(crate) fn from_line(line_number: usize, line: &str) -> Option<PeselBatchRecord> {
+        let input = line.trim();
+        if input.is_empty() {
+            return None;
+        }
+        Some(PeselBatchRecord {
+            line_number,
+            input: input.to_string(),
+            result: PESEL::from_str(input),
+        })
+    }
+
+    /// Utility function - same as `from_line`, but for raw bytes. Line which is not valid UTF-8 results in `PeselError::BadFormat`
+    /// pointing at the first undecodable byte (replaced with U+FFFD in `input`).
+    pub(crate) fn from_bytes(line_number: usize, line: &[u8]) -> Option<PeselBatchRecord> {
+        if let Ok(line) = std::str::from_utf8(line) {
+            return PeselBatchRecord::from_line(line_number, line);
+        }
+        let input = String::from_utf8_lossy(line).trim().to_string();
+        let index = input.find(char::REPLACEMENT_CHARACTER).expect("undecodable bytes are replaced with U+FFFD");
+        Some(PeselBatchRecord {
+            line_number,
+            input,
+            result: Err(PeselError::BadFormat { index, character: char::REPLACEMENT_CHARACTER }),
+        })
+    }
+}
+
+/// Utility function - reads next line (including line terminator) into `buffer`, returns `false` at the end of input
+pub(crate) fn read_line<R: BufRead>(reader: &mut R, buffer: &mut Vec<u8>) -> std::io::Result<bool> {
+    buffer.clear();
+    Ok(reader.read_until(b'\n', buffer)? > 0)
+}
+
+/// Iterator validating PESEL numbers read line by line (one number per line) from any `BufRead`.
+///
+/// Blank lines are skipped (but still counted, so line numbers always match the input). Lines which are not valid UTF-8
+/// are reported as `PeselError::BadFormat`. Reading stops on the first I/O error.
+pub struct PeselBatch<R> {
+    reader:         R,
+    buffer:         Vec<u8>,    // current line, reused to avoid allocations
+    line_number:    usize,
+    failed:         bool,
+}
+
+impl<R: BufRead> PeselBatch<R> {
+    pub fn new(reader: R) -> PeselBatch<R> {
+        PeselBatch {
+            reader,
+            buffer: Vec::new(),
+            line_number: 0,
+            failed: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for PeselBatch<R> {
+    type Item = std::io::Result<PeselBatchRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        loop {
+            match read_line(&mut self.reader, &mut self.buffer) {
+                Ok(true) => (),
+                Ok(false) => return None,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            };
+            self.line_number += 1;
+
+            if let Some(record) = PeselBatchRecord::from_bytes(self.line_number, &self.buffer) {
+                return Some(Ok(record));
+            }
+        }
+    }
+}
+
+/// Aggregated statistics of a batch validation
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PeselBatchSummary {
+    pub total:              usize,                          // number of (non-blank) lines processed
+    pub valid:              usize,                          // lines parsed with a correct checksum
+    pub invalid_checksum:   usize,                          // lines parsed, but failing checksum check
+    pub errors:             HashMap<PeselErrorKind, usize>, // lines that could not be parsed, per error kind
+}
+
+impl PeselBatchSummary {
+    /// Updates statistics with a single record
+    pub fn add(&mut self, record: &PeselBatchRecord) {
+        self.add_result(&record.result);
+    }
+
+    /// Updates statistics with a single parsing result
+    pub fn add_result(&mut self, result: &Result<PESEL, PeselError>) {
+        self.total += 1;
+        match result {
+            Ok(pesel) if pesel.is_valid() => self.valid += 1,
+            Ok(_) => self.invalid_checksum += 1,
+            Err(e) => *self.errors.entry(e.kind()).or_insert(0) += 1,
+        }
+    }
+
+    /// Adds statistics of another batch (i.e. computed for another part of the input) to these ones
+    pub fn merge(&mut self, other: &PeselBatchSummary) {
+        self.total += other.total;
+        self.valid += other.valid;
+        self.invalid_checksum += other.invalid_checksum;
+        for (kind, count) in &other.errors {
+            *self.errors.entry(*kind).or_insert(0) += count;
+        }
+    }
+
+    /// Returns number of lines which failed to parse because of given error kind
+    pub fn error_count(&self, kind: PeselErrorKind) -> usize {
+        self.errors.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Returns number of lines which could not be parsed at all
+    pub fn failed(&self) -> usize {
+        self.errors.values().sum()
+    }
+}
+
+impl PESEL {
+    /// Validates PESEL numbers read from `reader` (one number per line), lazily yielding a `PeselBatchRecord` for every non-blank line.
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    ///
+    /// let input = "44051401458\n44051401459\nnot a PESEL\n";
+    /// for record in PESEL::from_reader(input.as_bytes()) {
+    ///     let record = record.expect("unable to read input");
+    ///     match record.result {
+    ///         Ok(pesel) => println!("line {}: {} (valid: {})", record.line_number, pesel.pesel_number(), pesel.is_valid()),
+    ///         Err(e) => println!("line {}: {}", record.line_number, e),
+    ///     }
+    /// }
+    /// ```
+    pub fn from_reader<R: BufRead>(reader: R) -> PeselBatch<R> {
+        PeselBatch::new(reader)
+    }
+
+    /// Validates all PESEL numbers read from `reader` (one number per line) and returns aggregated statistics only.
+    /// Memory usage does not depend on the size of the input, so this is suitable for very large files.
+    pub fn summarize_reader<R: BufRead>(reader: R) -> std::io::Result<PeselBatchSummary> {
+        let mut summary = PeselBatchSummary::default();
+        for record in PESEL::from_reader(reader) {
+            summary.add(&record?);
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod pesel_batch_tests {
+    use crate::pesel::PESEL;
+    use crate::pesel_parsing_error::{PeselError, PeselErrorKind};
+
+    #[test]
+    fn every_non_blank_line_should_produce_a_record() {
+        let input = "44051401458\n\n  44051401465  \n";
+        let records: Vec<_> = PESEL::from_reader(input.as_bytes()).map(|r| r.unwrap()).collect();
+
+        assert_eq!(2, records.len());
+        assert_eq!(1, records[0].line_number);
+        assert_eq!(3, records[1].line_number);
+        assert_eq!("44051401465", records[1].input);
+    }
+
+    #[test]
+    fn records_should_report_checksum_status() {
+        let input = "44051401458\n44051401459\n4405140145a";
+        let records: Vec<_> = PESEL::from_reader(input.as_bytes()).map(|r| r.unwrap()).collect();
+
+        assert_eq!(Some(true), records[0].checksum_status());
+        assert_eq!(Some(false), records[1].checksum_status());
+        assert_eq!(None, records[2].checksum_status());
+        assert_eq!(PeselError::BadFormat { index: 10, character: 'a' }, *records[2].result.as_ref().unwrap_err());
+    }
+
+    #[test]
+    fn line_which_is_not_utf8_should_be_reported_as_bad_format() {
+        let input: &[u8] = b"44051401458\n4405\xB11401458\n44051401465\n";
+        let records: Vec<_> = PESEL::from_reader(input).map(|r| r.unwrap()).collect();
+
+        assert_eq!(3, records.len());
+        assert_eq!((2, "4405\u{FFFD}1401458"), (records[1].line_number, records[1].input.as_str()));
+        assert_eq!(PeselError::BadFormat { index: 4, character: '\u{FFFD}' }, *records[1].result.as_ref().unwrap_err());
+        assert!(records[2].is_valid());
+
+        let summary = PESEL::summarize_reader(input).unwrap();
+        assert_eq!((3, 2, 1), (summary.total, summary.valid, summary.error_count(PeselErrorKind::BadFormat)));
+    }
+
+    #[test]
+    fn windows_line_endings_should_be_handled() {
+        let input = "44051401458\r\n44051401465\r\n";
+        let summary = PESEL::summarize_reader(input.as_bytes()).unwrap();
+
+        assert_eq!(2, summary.total);
+        assert_eq!(2, summary.valid);
+    }
+
+    #[test]
+    fn summary_should_count_errors_per_kind() {
+        let input = "44051401458\n44051401459\n4405140145a\n440514\n44053201458\n44951201458\n1234567890123\n";
+        let summary = PESEL::summarize_reader(input.as_bytes()).unwrap();
+
+        assert_eq!(7, summary.total);
+        assert_eq!(1, summary.valid);
+        assert_eq!(1, summary.invalid_checksum);
+        assert_eq!(5, summary.failed());
+        assert_eq!(1, summary.error_count(PeselErrorKind::BadFormat));
+        assert_eq!(2, summary.error_count(PeselErrorKind::SizeError));
+        assert_eq!(1, summary.error_count(PeselErrorKind::InvalidDoB));
+        assert_eq!(1, summary.error_count(PeselErrorKind::DoBOutOfRange));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::pesel::{PESEL, PeselGender};
+use crate::pesel_parsing_error::PeselError;
+use core::str::FromStr;
+
+const PESEL_LENGTH: usize = 11;
+
+/// Compact, `Copy` representation of PESEL number: just its 11 ASCII digits, no heap allocation.
+///
+/// Ordering is the same as ordering of PESEL strings. Everything else is computed on demand from the digits,
+/// so prefer `PESEL` when the same number is inspected many times.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_compact::CompactPesel;
+/// use std::str::FromStr;
+///
+/// let pesel = CompactPesel::from_str("44051401458").unwrap();
+/// let copy = pesel;
+/// assert_eq!("44051401458", copy.as_str());
+/// assert!(pesel.is_valid());
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct CompactPesel {
+    digits:     [u8; PESEL_LENGTH],     // ASCII digits, already checked the same way `PESEL::from_str` does
+}
+
+impl CompactPesel {
+    /// Parses PESEL performing the same checks as `PESEL::from_str_strict` (numbers failing the checksum check are rejected).
+    /// Can be evaluated at compile time - see `pesel!` macro.
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel_compact::CompactPesel;
+    ///
+    /// const PESEL: Result<CompactPesel, pesel::pesel_parsing_error::PeselError> = CompactPesel::parse_strict("44051401458");
+    /// assert_eq!("44051401458", PESEL.unwrap().as_str());
+    /// ```
+    pub const fn parse_strict(s: &str) -> Result<CompactPesel, PeselError> {
+        let pesel = match CompactPesel::parse_lenient(s) {
+            Ok(pesel) => pesel,
+            Err(error) => return Err(error),
+        };
+        let d = &pesel.digits;
+        let expected = PESEL::calc_checksum(d[0] - b'0', d[1] - b'0', d[2] - b'0', d[3] - b'0', d[4] - b'0',
+            d[5] - b'0', d[6] - b'0', d[7] - b'0', d[8] - b'0', d[9] - b'0');
+        let found = d[10] - b'0';
+        match expected == found {
+            true => Ok(pesel),
+            false => Err(PeselError::InvalidChecksum { expected, found }),
+        }
+    }
+
+    /// Returns PESEL number as string slice
+    pub fn as_str(&self) -> &str {
+        // `digits` can only be filled by `parse_lenient` (after `PESEL::check_format`) or copied from a valid `PESEL`,
+        // so they are always ASCII digits - valid UTF-8 - and checking them again on every call is pointless
+        unsafe { core::str::from_utf8_unchecked(&self.digits) }
+    }
+
+    /// Returns PESEL number as ASCII digits
+    pub fn as_bytes(&self) -> &[u8; PESEL_LENGTH] {
+        &self.digits
+    }
+
+    /// Checks if checksum is correct
+    pub fn is_valid(&self) -> bool {
+        PESEL::calc_checksum_from_pesel_string(self.as_str()) == self.digit(10)
+    }
+
+    /// Returns biological gender
+    pub fn gender(&self) -> PeselGender {
+        PESEL::gender_from_digit(self.digit(9))
+    }
+
+    /// Utility function - returns value of digit at given position
+    const fn digit(&self, index: usize) -> u8 {
+        self.digits[index] - b'0'
+    }
+
+    /// Utility function - performs the same checks as `PESEL::from_str` (numbers failing the checksum check are accepted)
+    const fn parse_lenient(s: &str) -> Result<CompactPesel, PeselError> {
+        if let Err(error) = PESEL::check_format(s) {
+            return Err(error);
+        }
+        let mut digits = [0; PESEL_LENGTH];
+        let mut index = 0;
+        while index < PESEL_LENGTH {
+            digits[index] = s.as_bytes()[index];
+            index += 1;
+        }
+        let pesel = CompactPesel { digits };
+        match PESEL::check_encoded_date(pesel.digit(0) * 10 + pesel.digit(1), pesel.digit(2) * 10 + pesel.digit(3), pesel.digit(4) * 10 + pesel.digit(5)) {
+            Ok(()) => Ok(pesel),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Creates `CompactPesel` constant from string literal, validating format, date of birth and checksum at compile time
+/// (invalid PESEL fails the build).
+///
+/// Note: macro returns `CompactPesel`, not `PESEL` - use `PESEL::from` to convert it at run time.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel::PESEL;
+/// use pesel::pesel_compact::CompactPesel;
+///
+/// const ADMIN: CompactPesel = pesel::pesel!("44051401458");
+/// assert_eq!("44051401458", ADMIN.as_str());
+///
+/// let admin = PESEL::from(ADMIN);
+/// assert!(admin.is_valid());
+/// ```
+///
+/// ```compile_fail
+/// // checksum is wrong
+/// const ADMIN: pesel::pesel_compact::CompactPesel = pesel::pesel!("44051401459");
+/// ```
+#[macro_export]
+macro_rules! pesel {
+    ($number:expr) => {{
+        const PESEL: $crate::pesel_compact::CompactPesel = match $crate::pesel_compact::CompactPesel::parse_strict($number) {
+            ::core::result::Result::Ok(pesel) => pesel,
+            ::core::result::Result::Err(error) => ::core::panic!("{}", error.pesel_error_to_message()),
+        };
+        PESEL
+    }};
+}
+
+impl FromStr for CompactPesel {
+    type Err = PeselError;
+
+    /// Performs the same checks as `PESEL::from_str` (in particular, numbers failing the checksum check are accepted)
+    fn from_str(s: &str) -> Result<CompactPesel, PeselError> {
+        CompactPesel::parse_lenient(s)
+    }
+}
+
+impl core::fmt::Display for CompactPesel {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&PESEL> for CompactPesel {
+    fn from(pesel: &PESEL) -> CompactPesel {
+        let mut digits = [0; PESEL_LENGTH];
+        digits.copy_from_slice(pesel.as_str().as_bytes());
+        CompactPesel { digits }
+    }
+}
+
+impl From<PESEL> for CompactPesel {
+    fn from(pesel: PESEL) -> CompactPesel {
+        CompactPesel::from(&pesel)
+    }
+}
+
+impl From<CompactPesel> for PESEL {
+    fn from(pesel: CompactPesel) -> PESEL {
+        PESEL::from_str(pesel.as_str()).expect("CompactPesel has been checked when it was created")
+    }
+}
+
+#[cfg(test)]
+mod pesel_compact_tests {
+    use super::CompactPesel;
+    use crate::pesel::{PESEL, PeselGender};
+    use crate::pesel_parsing_error::PeselError;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    #[test]
+    fn compact_pesel_should_perform_same_checks_as_pesel() {
+        for input in &["44051401458", "44051401459", "4405140145a", "440514014", "44053201458", "44951201458"] {
+            let compact = CompactPesel::from_str(input);
+            let pesel = PESEL::from_str(input);
+
+            assert_eq!(pesel.as_ref().err(), compact.as_ref().err());
+            assert_eq!(pesel.map(|p| p.is_valid()).ok(), compact.map(|p| p.is_valid()).ok());
+        }
+    }
+
+    #[test]
+    fn compact_pesel_should_convert_to_and_from_pesel() {
+        let pesel = PESEL::from_str("02270803624").unwrap();
+        let compact = CompactPesel::from(&pesel);
+
+        assert_eq!("02270803624", compact.as_str());
+        assert_eq!(PeselGender::Female, compact.gender());
+        assert_eq!(pesel.as_str(), PESEL::from(compact).as_str());
+    }
+
+    #[test]
+    fn compact_pesels_should_be_ordered_and_hashable() {
+        let a = CompactPesel::from_str("02270803624").unwrap();
+        let b = CompactPesel::from_str("44051401458").unwrap();
+        let set: HashSet<CompactPesel> = vec![a, b, a].into_iter().collect();
+
+        assert!(a < b);
+        assert_eq!(2, set.len());
+        assert_eq!("44051401458", b.to_string());
+        assert_eq!(Err(PeselError::SizeError { expected: 11, found: 0 }), CompactPesel::from_str(""));
+    }
+
+    #[test]
+    fn pesel_macro_should_create_constant() {
+        const PESEL: CompactPesel = pesel!("02270803624");
+
+        assert_eq!("02270803624", PESEL.as_str());
+        assert_eq!(PeselGender::Female, PESEL.gender());
+    }
+
+    #[test]
+    fn const_parsing_should_reject_invalid_checksum() {
+        const INVALID: Result<CompactPesel, PeselError> = CompactPesel::parse_strict("44051401459");
+
+        assert_eq!(Err(PeselError::InvalidChecksum { expected: 8, found: 9 }), INVALID);
+        assert_eq!(Err(PeselError::InvalidDoB { year: 1944, month: 5, day: 32 }), CompactPesel::parse_strict("44053201458"));
+    }
+
+    #[test]
+    fn non_ascii_character_should_be_reported() {
+        assert_eq!(Err(PeselError::BadFormat { index: 9, character: 'ą' }), CompactPesel::parse_strict("440514014ą"));
+        assert_eq!(PeselError::BadFormat { index: 9, character: 'ą' }, PESEL::from_str("440514014ą").unwrap_err());
+    }
+}
@@ -0,0 +1,206 @@
+use crate::pesel::{PESEL, PeselGender};
+use crate::pesel_parsing_error::PeselError;
+use alloc::format;
+use core::str::FromStr;
+
+const CHECK_DIGIT_INDEX: usize = 10;
+
+/// PESEL number decomposed into its components.
+///
+/// Layout of PESEL digits: `YYMMDDSSSGC`, where `YY` - year of birth within century, `MM` - month of birth with century offset added,
+/// `DD` - day of birth, `SSS` - serial number, `G` - gender digit (odd for men, even for women), `C` - check digit.
+/// `SSSG` together are called ordinal number.
+///
+/// Components are always consistent (date of birth is valid, serial and gender digit are in range), only the check digit may be wrong.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel::PESEL as PESEL;
+/// use std::str::FromStr;
+///
+/// let components = PESEL::from_str("02270803624").unwrap().components();
+/// assert_eq!((2, 27, 2000, 8), (components.encoded_year(), components.encoded_month(), components.century(), components.day()));
+/// assert_eq!((36, 2, 362, 4), (components.serial(), components.gender_digit(), components.ordinal(), components.check_digit()));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct PeselComponents {
+    encoded_year:   u8,         // digits 1-2: year of birth within century (0-99)
+    encoded_month:  u8,         // digits 3-4: month of birth, with century offset
+    day:            u8,         // digits 5-6: day of birth
+    ordinal:        u16,        // digits 7-10: serial number followed by gender digit
+    check_digit:    u8,         // digit 11
+}
+
+impl PeselComponents {
+    /// Creates components from birth date and 4-digit ordinal number (serial followed by gender digit, 0..=9999).
+    /// Check digit is calculated, so PESEL built from these components is valid.
+    ///
+    /// Returns `PeselError::InvalidDoB` or `PeselError::DoBOutOfRange` for dates which cannot be represented in PESEL,
+    /// and `PeselError::InvalidSerial` if ordinal is out of range.
+    pub fn new(year: u16, month: u8, day: u8, ordinal: u16) -> Result<PeselComponents, PeselError> {
+        let pesel = PESEL::with_ordinal(year, month, day, ordinal, PESEL::gender_from_digit((ordinal % 10) as u8))?;
+        Ok(pesel.components())
+    }
+
+    /// Replaces check digit (i.e. to reassemble PESEL failing the checksum check)
+    ///
+    /// Panics if `check_digit` is greater than 9.
+    pub fn with_check_digit(mut self, check_digit: u8) -> PeselComponents {
+        assert!(check_digit <= 9, "check digit has to be a single digit");
+        self.check_digit = check_digit;
+        self
+    }
+
+    /// Returns year of birth within century, as encoded in PESEL (digits 1-2)
+    pub fn encoded_year(&self) -> u8 {
+        self.encoded_year
+    }
+
+    /// Returns month of birth with century offset, as encoded in PESEL (digits 3-4), i.e. 27 for July 2002
+    pub fn encoded_month(&self) -> u8 {
+        self.encoded_month
+    }
+
+    /// Returns first year of the century decoded from month, i.e. 2000 for July 2002
+    pub fn century(&self) -> u16 {
+        self.birth_year() - self.encoded_year as u16
+    }
+
+    /// Returns year of birth (1800-2299)
+    pub fn birth_year(&self) -> u16 {
+        PESEL::calc_year_from_pesel_encoded_month_and_year(self.encoded_year, self.encoded_month) as u16
+    }
+
+    /// Returns month of birth (1-12)
+    pub fn birth_month(&self) -> u8 {
+        self.encoded_month % 20
+    }
+
+    /// Returns day of birth (digits 5-6)
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns 3-digit serial number (digits 7-9)
+    pub fn serial(&self) -> u16 {
+        self.ordinal / 10
+    }
+
+    /// Returns gender digit (digit 10)
+    pub fn gender_digit(&self) -> u8 {
+        (self.ordinal % 10) as u8
+    }
+
+    /// Returns biological gender coded by gender digit
+    pub fn gender(&self) -> PeselGender {
+        PESEL::gender_from_digit(self.gender_digit())
+    }
+
+    /// Returns 4-digit ordinal number: serial followed by gender digit (digits 7-10)
+    pub fn ordinal(&self) -> u16 {
+        self.ordinal
+    }
+
+    /// Returns check digit (digit 11)
+    pub fn check_digit(&self) -> u8 {
+        self.check_digit
+    }
+}
+
+/// Calculates check digit from the first 10 digits of PESEL. `s` has to consist of exactly 10 ASCII digits
+/// (length is counted in bytes, same as by `PESEL::from_str`). Date of birth is not checked.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_components::check_digit;
+///
+/// assert_eq!(Ok(8), check_digit("4405140145"));
+/// assert!(check_digit("44051401459").is_err());
+/// ```
+pub fn check_digit(s: &str) -> Result<u8, PeselError> {
+    if s.len() != CHECK_DIGIT_INDEX {
+        return Err(PeselError::SizeError { expected: CHECK_DIGIT_INDEX, found: s.len() });
+    }
+    if let Some((index, character)) = s.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(PeselError::BadFormat { index, character });
+    }
+    Ok(PESEL::calc_checksum_from_pesel_string(s))
+}
+
+impl PESEL {
+    /// Returns all components of PESEL number (see `PeselComponents`)
+    pub fn components(&self) -> PeselComponents {
+        let number = |range: core::ops::Range<usize>| self.as_str()[range].parse::<u16>().expect("PESEL consists of digits only");
+        PeselComponents {
+            encoded_year: number(0..2) as u8,
+            encoded_month: number(2..4) as u8,
+            day: self.birth_day(),
+            ordinal: number(6..CHECK_DIGIT_INDEX),
+            check_digit: self.check_digit(),
+        }
+    }
+
+    /// Reassembles PESEL from components. Check digit is taken as it is, so the result may fail the checksum check
+    /// (see `PeselComponents::with_check_digit`).
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    /// use pesel::pesel_components::PeselComponents;
+    ///
+    /// let components = PeselComponents::new(1944, 5, 14, 145).unwrap();
+    /// assert_eq!("44051401458", PESEL::from_components(components).pesel_number());
+    /// assert!(!PESEL::from_components(components.with_check_digit(9)).is_valid());
+    /// ```
+    pub fn from_components(components: PeselComponents) -> PESEL {
+        let pesel_string = format!("{:02}{:02}{:02}{:04}{}", components.encoded_year, components.encoded_month, components.day,
+            components.ordinal, components.check_digit);
+        PESEL::from_str(&pesel_string).expect("components have been checked when they were created")
+    }
+}
+
+#[cfg(test)]
+mod pesel_components_tests {
+    use super::{check_digit, PeselComponents};
+    use crate::pesel::{PESEL, PeselGender};
+    use crate::pesel_parsing_error::PeselError;
+    use std::str::FromStr;
+
+    #[test]
+    fn pesel_should_be_decomposed() {
+        let components = PESEL::from_str("44051401458").unwrap().components();
+
+        assert_eq!((44, 5, 1900, 1944, 5, 14), (components.encoded_year(), components.encoded_month(), components.century(),
+            components.birth_year(), components.birth_month(), components.day()));
+        assert_eq!((14, 5, 145, 8), (components.serial(), components.gender_digit(), components.ordinal(), components.check_digit()));
+        assert_eq!(PeselGender::Male, components.gender());
+    }
+
+    #[test]
+    fn pesel_should_be_reassembled_from_components() {
+        for number in &["44051401458", "44051401459", "02270803624", "00810100007"] {
+            let pesel = PESEL::from_str(number).unwrap();
+            let reassembled = PESEL::from_components(pesel.components());
+
+            assert_eq!(pesel.as_str(), reassembled.as_str());
+            assert_eq!(pesel.is_valid(), reassembled.is_valid());
+        }
+    }
+
+    #[test]
+    fn components_should_be_validated() {
+        assert_eq!(2200, PeselComponents::new(2299, 12, 31, 9999).unwrap().century());
+        assert_eq!(Err(PeselError::InvalidDoB { year: 1993, month: 2, day: 29 }), PeselComponents::new(1993, 2, 29, 1));
+        assert_eq!(Err(PeselError::InvalidSerial), PeselComponents::new(1944, 5, 14, 10000));
+    }
+
+    #[test]
+    fn check_digit_should_be_calculated() {
+        assert_eq!(Ok(4), check_digit("0227080362"));
+        assert_eq!(Err(PeselError::SizeError { expected: 10, found: 11 }), check_digit("02270803624"));
+        assert_eq!(Err(PeselError::SizeError { expected: 10, found: 9 }), check_digit("022708036"));
+        assert_eq!(Err(PeselError::SizeError { expected: 10, found: 11 }), check_digit("022708036ą"));
+        assert_eq!(Err(PeselError::BadFormat { index: 3, character: 'x' }), check_digit("022x080362"));
+        assert_eq!(Err(PeselError::BadFormat { index: 8, character: 'ą' }), check_digit("02270803ą"));
+    }
+}
@@ -0,0 +1,248 @@
+use crate::pesel::PESEL;
+use crate::pesel_parsing_error::PeselError;
+use alloc::vec::Vec;
+use core::str::FromStr;
+#[cfg(feature = "chrono")]
+use alloc::string::String;
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate};
+
+/// People older than that are considered unlikely to be still using their PESEL
+#[cfg(feature = "chrono")]
+const MAX_PLAUSIBLE_AGE: i32 = 120;
+
+/// Character marking missing or illegible digit in patterns accepted by `PESEL::complete`
+pub const WILDCARD: char = '?';
+
+/// Maximum number of wildcards in patterns accepted by `PESEL::complete`
+pub const MAX_WILDCARDS: usize = 2;
+
+const CHECKSUM_INDEX: usize = 10;
+
+/// Typo which, when fixed, turns the input into a valid PESEL
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PeselTypo {
+    /// Digit at `index` has been mistyped: `found` was typed instead of `expected`
+    Substitution { index: usize, found: char, expected: char },
+    /// Digits at `index` and `index + 1` have been swapped
+    Transposition { index: usize },
+}
+
+/// Valid PESEL suggested as correction of an invalid one (see `PESEL::suggest_corrections`)
+#[derive(Debug)]
+pub struct PeselCorrection {
+    pub pesel:  PESEL,          // corrected PESEL (passing checksum check)
+    pub typo:   PeselTypo,      // typo the correction fixes
+}
+
+impl PESEL {
+    /// Suggests valid PESEL numbers reachable from `s` by fixing a single typo: one mistyped digit or two swapped adjacent digits.
+    /// Only numbers passing the checksum check and decoding to a real date within PESEL range are returned.
+    ///
+    /// Suggestions are ranked by plausibility: swapped digits first, then digits mistyped as their neighbours (i.e. 4 instead of 5),
+    /// then other mistyped digits. Within every group numbers decoding to a birth date in the future or more than 120 years ago go last.
+    ///
+    /// Returns `PeselError::SizeError` or `PeselError::BadFormat` if `s` is not an 11 digit string. For numbers which are valid already,
+    /// list of suggestions is empty.
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    /// use pesel::pesel_correction::PeselTypo;
+    ///
+    /// let corrections = PESEL::suggest_corrections("44051401485").unwrap();
+    /// assert_eq!("44051401458", corrections[0].pesel.pesel_number());
+    /// assert_eq!(PeselTypo::Transposition { index: 9 }, corrections[0].typo);
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn suggest_corrections(s: &str) -> Result<Vec<PeselCorrection>, PeselError> {
+        PESEL::check_format(s)?;
+        if PESEL::from_str(s).map(|pesel| pesel.is_valid()).unwrap_or(false) {
+            return Ok(Vec::new());
+        }
+
+        let digits: Vec<u8> = s.bytes().collect();
+        let mut candidates = Vec::new();
+        for index in 0..digits.len() {
+            for digit in b'0'..=b'9' {
+                if digit != digits[index] {
+                    let mut candidate = digits.clone();
+                    candidate[index] = digit;
+                    candidates.push((candidate, PeselTypo::Substitution { index, found: digits[index] as char, expected: digit as char }));
+                }
+            }
+            if index + 1 < digits.len() && digits[index] != digits[index + 1] {
+                let mut candidate = digits.clone();
+                candidate.swap(index, index + 1);
+                candidates.push((candidate, PeselTypo::Transposition { index }));
+            }
+        }
+
+        let today = PESEL::today();
+        let mut corrections: Vec<(u8, PeselCorrection)> = candidates.into_iter()
+            .filter_map(|(candidate, typo)| {
+                let candidate = String::from_utf8(candidate).expect("candidate consists of ASCII digits only");
+                let pesel = PESEL::from_str(&candidate).ok().filter(|pesel| pesel.is_valid())?;
+                Some((PESEL::typo_penalty(typo, pesel.date_of_birth(), today), PeselCorrection { pesel, typo }))
+            })
+            .collect();
+        corrections.sort_by(|(a_penalty, a), (b_penalty, b)| a_penalty.cmp(b_penalty).then_with(|| a.pesel.pesel_number().cmp(&b.pesel.pesel_number())));
+
+        Ok(corrections.into_iter().map(|(_, correction)| correction).collect())
+    }
+
+    /// Returns all valid PESEL numbers matching `pattern`, in which missing or illegible digits are replaced with `?` (at most two of them).
+    /// Only numbers passing the checksum check and decoding to a real date within PESEL range are returned, in ascending order.
+    ///
+    /// Returns `PeselError::SizeError` if pattern is not 11 characters long, and `PeselError::BadFormat` if it contains anything but digits
+    /// and wildcards (the third wildcard is reported as `PeselError::BadFormat` too).
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    ///
+    /// let completions = PESEL::complete("4405140?458").unwrap();
+    /// assert_eq!(vec!["44051401458"], completions.iter().map(|p| p.pesel_number()).collect::<Vec<_>>());
+    /// ```
+    pub fn complete(pattern: &str) -> Result<Vec<PESEL>, PeselError> {
+        let wildcards = PESEL::find_wildcards(pattern)?;
+        let mut digits: Vec<u8> = pattern.bytes().collect();
+        let mut completions = Vec::new();
+
+        // checksum digit (if missing) is calculated rather than guessed
+        let guessed: Vec<usize> = wildcards.iter().copied().filter(|index| *index != CHECKSUM_INDEX).collect();
+        for combination in 0..10usize.pow(guessed.len() as u32) {
+            let mut rest = combination;
+            for index in guessed.iter().rev() {
+                digits[*index] = b'0' + (rest % 10) as u8;
+                rest /= 10;
+            }
+            let checksum = PESEL::calc_checksum_from_pesel_string(core::str::from_utf8(&digits).expect("pattern consists of ASCII characters only"));
+            if wildcards.contains(&CHECKSUM_INDEX) {
+                digits[CHECKSUM_INDEX] = b'0' + checksum;
+            } else if digits[CHECKSUM_INDEX] != b'0' + checksum {
+                continue;
+            }
+            if let Ok(pesel) = PESEL::from_str(core::str::from_utf8(&digits).expect("pattern consists of ASCII characters only")) {
+                completions.push(pesel);
+            }
+        }
+
+        Ok(completions)
+    }
+
+    /// Utility function - returns positions of wildcards, making sure pattern is 11 characters long and contains only digits and wildcards
+    fn find_wildcards(pattern: &str) -> Result<Vec<usize>, PeselError> {
+        if pattern.len() != CHECKSUM_INDEX + 1 {
+            return Err(PeselError::SizeError { expected: CHECKSUM_INDEX + 1, found: pattern.len() });
+        }
+        let mut wildcards = Vec::new();
+        for (index, character) in pattern.char_indices() {
+            match character {
+                WILDCARD if wildcards.len() < MAX_WILDCARDS => wildcards.push(index),
+                '0'..='9' => (),
+                _ => return Err(PeselError::BadFormat { index, character }),
+            }
+        }
+        Ok(wildcards)
+    }
+
+    /// Utility function - the less plausible the typo, the higher the penalty
+    #[cfg(feature = "chrono")]
+    fn typo_penalty(typo: PeselTypo, date_of_birth: NaiveDate, today: NaiveDate) -> u8 {
+        let typo_penalty = match typo {
+            PeselTypo::Transposition { .. } => 0,
+            PeselTypo::Substitution { found, expected, .. } if (found as i8 - expected as i8).abs() == 1 => 1,
+            PeselTypo::Substitution { .. } => 2,
+        };
+        let plausible_date = date_of_birth <= today && today.year() - date_of_birth.year() <= MAX_PLAUSIBLE_AGE;
+        match plausible_date {
+            true => typo_penalty,
+            false => typo_penalty + 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod pesel_correction_tests {
+    #[cfg(feature = "chrono")]
+    use super::PeselTypo;
+    use crate::pesel::PESEL;
+    use crate::pesel_parsing_error::PeselError;
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn swapped_digits_should_be_suggested_first() {
+        let corrections = PESEL::suggest_corrections("44051401485").unwrap();
+
+        assert_eq!("44051401458", corrections[0].pesel.pesel_number());
+        assert_eq!(PeselTypo::Transposition { index: 9 }, corrections[0].typo);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn mistyped_digit_should_be_suggested() {
+        let corrections = PESEL::suggest_corrections("44051401459").unwrap();
+
+        assert!(corrections.iter().all(|c| c.pesel.is_valid()));
+        let fixed = corrections.iter().find(|c| c.pesel.pesel_number() == "44051401458").unwrap();
+        assert_eq!(PeselTypo::Substitution { index: 10, found: '9', expected: '8' }, fixed.typo);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn suggestions_should_decode_to_real_dates() {
+        // 31st of June: every suggestion has to fix the date and the checksum at the same time
+        let corrections = PESEL::suggest_corrections("80063144451").unwrap();
+
+        assert!(!corrections.is_empty());
+        assert!(corrections.iter().all(|c| c.pesel.is_valid() && !c.pesel.pesel_number().starts_with("800631")));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn valid_pesel_should_have_no_suggestions() {
+        assert!(PESEL::suggest_corrections("44051401458").unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn malformed_input_should_result_in_error() {
+        assert_eq!(PeselError::SizeError { expected: 11, found: 10 }, PESEL::suggest_corrections("4405140145").unwrap_err());
+        assert_eq!(PeselError::BadFormat { index: 10, character: 'a' }, PESEL::suggest_corrections("4405140145a").unwrap_err());
+    }
+
+    #[test]
+    fn single_missing_digit_should_be_recovered() {
+        let completions: Vec<String> = PESEL::complete("4405140?458").unwrap().iter().map(|p| p.pesel_number()).collect();
+
+        assert_eq!(vec!["44051401458"], completions);
+    }
+
+    #[test]
+    fn missing_checksum_digit_should_be_calculated() {
+        let completions: Vec<String> = PESEL::complete("4405140145?").unwrap().iter().map(|p| p.pesel_number()).collect();
+        assert_eq!(vec!["44051401458"], completions);
+
+        let completions = PESEL::complete("44051401?5?").unwrap();
+        assert_eq!(10, completions.len());
+        assert!(completions.iter().all(|p| p.is_valid()));
+    }
+
+    #[test]
+    fn completions_should_decode_to_real_dates() {
+        // day of birth "?0" in February: 00th and 30th do not exist
+        let completions: Vec<String> = PESEL::complete("4402?00145?").unwrap().iter().map(|p| p.pesel_number()).collect();
+
+        assert_eq!(2, completions.len());
+        assert!(completions[0].starts_with("440210"));
+        assert!(completions[1].starts_with("440220"));
+    }
+
+    #[test]
+    fn more_than_two_wildcards_should_result_in_error() {
+        assert_eq!(PeselError::BadFormat { index: 10, character: '?' }, PESEL::complete("4405140?4??").unwrap_err());
+        assert_eq!(PeselError::BadFormat { index: 0, character: 'x' }, PESEL::complete("x405140?458").unwrap_err());
+        assert_eq!(PeselError::SizeError { expected: 11, found: 10 }, PESEL::complete("405140?458").unwrap_err());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::pesel::{PESEL, PeselGender};
+use crate::pesel_parsing_error::PeselError;
+use chrono::{Datelike, NaiveDate};
+
+/// Number of different ordinals (serial + gender digit) available for one gender on a given day
+pub const PESELS_PER_DAY_AND_GENDER: usize = 5000;
+
+const MAX_ORDINAL: u16 = 9999;
+
+/// Lazy iterator over all valid PESEL numbers for given gender and range of birth dates (see `PESEL::all_for_date` and `PESEL::all_for_date_range`).
+///
+/// Dates are visited in chronological order, and for every date numbers are yielded in ascending order.
+/// Note: within a single century this means the whole sequence is ascending, but it is not the case for ranges spanning multiple
+/// centuries (century is encoded in month, i.e. 1899-12-31 is coded as 999231..., while 1900-01-01 as 000101...).
+#[derive(Debug, Clone)]
+pub struct PeselEnumerator {
+    date:       Option<NaiveDate>,      // current date, None when iteration is finished
+    last:       NaiveDate,              // last date (inclusive)
+    gender:     PeselGender,
+    ordinal:    u16,                    // next ordinal to be used for current date
+}
+
+impl PeselEnumerator {
+    fn new(first: NaiveDate, last: NaiveDate, gender: PeselGender) -> PeselEnumerator {
+        PeselEnumerator {
+            date: if first <= last { Some(first) } else { None },
+            last,
+            gender,
+            ordinal: PeselEnumerator::first_ordinal(gender),
+        }
+    }
+
+    /// Utility function - smallest ordinal number matching gender (even for women, odd for men)
+    fn first_ordinal(gender: PeselGender) -> u16 {
+        match gender {
+            PeselGender::Female => 0,
+            PeselGender::Male => 1,
+        }
+    }
+
+    /// Utility function - number of PESELs not yet yielded
+    fn remaining(&self) -> usize {
+        match self.date {
+            Some(date) => {
+                let days_left = (self.last - date).num_days() as usize;
+                let left_for_current_day = (MAX_ORDINAL - self.ordinal) as usize / 2 + 1;
+                days_left * PESELS_PER_DAY_AND_GENDER + left_for_current_day
+            },
+            None => 0,
+        }
+    }
+}
+
+impl Iterator for PeselEnumerator {
+    type Item = PESEL;
+
+    fn next(&mut self) -> Option<PESEL> {
+        let date = self.date?;
+        let pesel = PESEL::with_ordinal(date.year() as u16, date.month() as u8, date.day() as u8, self.ordinal, self.gender)
+            .expect("date range has been validated when enumerator was created");
+
+        if self.ordinal + 2 > MAX_ORDINAL {
+            self.ordinal = PeselEnumerator::first_ordinal(self.gender);
+            self.date = date.succ_opt().filter(|next| *next <= self.last);
+        } else {
+            self.ordinal += 2;
+        }
+        Some(pesel)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for PeselEnumerator {}
+
+impl PESEL {
+    /// Returns lazy iterator over all 5000 valid PESEL numbers for given birth date and gender, in ascending order.
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::{PESEL as PESEL, PeselGender};
+    ///
+    /// let all = PESEL::all_for_date(1944, 5, 14, PeselGender::Male).unwrap();
+    /// assert_eq!(5000, all.len());
+    /// ```
+    pub fn all_for_date(year: u16, month: u8, day: u8, pesel_gender: PeselGender) -> Result<PeselEnumerator, PeselError> {
+        let date = PESEL::naive_date(year as i32, month as u32, day as u32)?;
+        Ok(PeselEnumerator::new(date, date, pesel_gender))
+    }
+
+    /// Returns lazy iterator over all valid PESEL numbers for given gender and birth dates between `first` and `last` (both inclusive).
+    /// If `first` is later than `last` the iterator is empty. Both dates have to be within PESEL range (1800-2299).
+    pub fn all_for_date_range(first: NaiveDate, last: NaiveDate, pesel_gender: PeselGender) -> Result<PeselEnumerator, PeselError> {
+        let first = PESEL::naive_date(first.year(), first.month(), first.day())?;
+        let last = PESEL::naive_date(last.year(), last.month(), last.day())?;
+        Ok(PeselEnumerator::new(first, last, pesel_gender))
+    }
+
+    /// Utility function - returns date, provided it is valid and within PESEL range
+    fn naive_date(year: i32, month: u32, day: u32) -> Result<NaiveDate, PeselError> {
+        if ! PESEL::is_date_in_range(year) {
+            return Err(PeselError::DoBOutOfRange { year });
+        }
+        NaiveDate::from_ymd_opt(year, month, day).ok_or(PeselError::InvalidDoB { year, month: month as u8, day: day as u8 })
+    }
+}
+
+#[cfg(test)]
+mod pesel_enumeration_tests {
+    use crate::pesel::{PESEL, PeselGender};
+    use crate::pesel_parsing_error::PeselErrorKind;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn all_pesels_for_date_should_be_valid_and_ascending() {
+        let all: Vec<PESEL> = PESEL::all_for_date(1944, 5, 14, PeselGender::Male).unwrap().collect();
+
+        assert_eq!(5000, all.len());
+        assert_eq!("44051400013", all[0].pesel_number());
+        assert!(all.iter().any(|p| p.pesel_number() == "44051401458"));
+        assert!(all.iter().all(|p| p.is_valid() && p.gender() == PeselGender::Male));
+        assert!(all.windows(2).all(|w| w[0].pesel_number() < w[1].pesel_number()));
+    }
+
+    #[test]
+    fn female_pesels_should_use_even_gender_digits() {
+        let all: Vec<PESEL> = PESEL::all_for_date(2002, 7, 8, PeselGender::Female).unwrap().collect();
+
+        assert_eq!(5000, all.len());
+        assert_eq!("02270800003", all[0].pesel_number());
+        assert!(all.iter().all(|p| p.gender() == PeselGender::Female));
+    }
+
+    #[test]
+    fn date_range_should_be_inclusive() {
+        let first = NaiveDate::from_ymd_opt(1999, 12, 30).unwrap();
+        let last = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let mut all = PESEL::all_for_date_range(first, last, PeselGender::Female).unwrap();
+
+        assert_eq!(15000, all.len());
+        assert_eq!("99123000006", all.next().unwrap().pesel_number());
+        assert_eq!(14999, all.len());
+        assert_eq!("00210199987", all.last().unwrap().pesel_number());
+    }
+
+    #[test]
+    fn empty_range_should_yield_nothing() {
+        let first = NaiveDate::from_ymd_opt(2000, 1, 2).unwrap();
+        let last = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+
+        assert_eq!(0, PESEL::all_for_date_range(first, last, PeselGender::Male).unwrap().count());
+    }
+
+    #[test]
+    fn invalid_dates_should_result_in_error() {
+        assert_eq!(PeselErrorKind::InvalidDoB, PESEL::all_for_date(1993, 2, 29, PeselGender::Male).unwrap_err().kind());
+        assert_eq!(PeselErrorKind::DoBOutOfRange, PESEL::all_for_date(2300, 1, 1, PeselGender::Male).unwrap_err().kind());
+
+        let first = NaiveDate::from_ymd_opt(1799, 12, 31).unwrap();
+        let last = NaiveDate::from_ymd_opt(1800, 1, 1).unwrap();
+        assert_eq!(PeselErrorKind::DoBOutOfRange, PESEL::all_for_date_range(first, last, PeselGender::Male).unwrap_err().kind());
+    }
+}
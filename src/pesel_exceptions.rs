@@ -0,0 +1,228 @@
+use crate::pesel::PESEL;
+use crate::pesel_parsing_error::PeselError;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::io::{BufRead, BufReader};
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Validity status of PESEL number, taking known exceptions into account
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum PeselValidity {
+    /// Checksum is correct
+    Valid,
+    /// Checksum is incorrect and the number is not a known exception
+    InvalidChecksum,
+    /// Checksum is incorrect, but the number has been officially issued (it is in the exception registry)
+    RecognizedException,
+}
+
+impl PeselValidity {
+    /// Checks if the number should be accepted (valid or recognized exception)
+    pub fn is_accepted(&self) -> bool {
+        *self != PeselValidity::InvalidChecksum
+    }
+}
+
+/// Error returned when exception registry can not be loaded
+#[derive(Debug)]
+pub enum PeselRegistryError {
+    /// Entry is not a PESEL number (see `PESEL::from_str`). Contains line number (or position in collection), counting from 1.
+    InvalidEntry { line_number: usize, error: PeselError },
+    /// Registry could not be read
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+}
+
+impl core::fmt::Display for PeselRegistryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            PeselRegistryError::InvalidEntry { line_number, error } => write!(f, "line {}: {}", line_number, error),
+            #[cfg(feature = "std")]
+            PeselRegistryError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PeselRegistryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PeselRegistryError::InvalidEntry { error, .. } => Some(error),
+            PeselRegistryError::Io(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for PeselRegistryError {
+    fn from(error: std::io::Error) -> PeselRegistryError {
+        PeselRegistryError::Io(error)
+    }
+}
+
+/// Registry of PESEL numbers which fail the checksum check, but are known to have been officially issued (and are still in use).
+/// Every entry has to be a PESEL number (see `PESEL::from_str`), only its checksum may be wrong.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel::PESEL as PESEL;
+/// use pesel::pesel_exceptions::{PeselExceptionRegistry, PeselValidity};
+/// use std::str::FromStr;
+///
+/// let registry = PeselExceptionRegistry::from_numbers(vec!["44051401459"]).unwrap();
+/// let pesel = PESEL::from_str("44051401459").unwrap();
+/// assert_eq!(PeselValidity::RecognizedException, pesel.validity(&registry));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PeselExceptionRegistry {
+    numbers:    BTreeSet<String>,   // 11 digit PESEL numbers
+}
+
+impl PeselExceptionRegistry {
+    /// Creates empty registry
+    pub fn new() -> PeselExceptionRegistry {
+        PeselExceptionRegistry::default()
+    }
+
+    /// Creates registry from a collection of PESEL numbers.
+    ///
+    /// Returns `PeselRegistryError::InvalidEntry` (with position of the entry, counting from 1) for the first string which is not a PESEL number.
+    pub fn from_numbers<I>(numbers: I) -> Result<PeselExceptionRegistry, PeselRegistryError> where I: IntoIterator, I::Item: AsRef<str> {
+        let mut registry = PeselExceptionRegistry::new();
+        for (index, number) in numbers.into_iter().enumerate() {
+            registry.insert(number.as_ref()).map_err(|error| PeselRegistryError::InvalidEntry { line_number: index + 1, error })?;
+        }
+        Ok(registry)
+    }
+
+    /// Reads registry from `reader`: one PESEL number per line, surrounding whitespace is trimmed,
+    /// blank lines and lines starting with `#` (comments) are skipped. Requires `std` feature.
+    ///
+    /// Returns `PeselRegistryError::InvalidEntry` (with line number) for the first line which is not a PESEL number,
+    /// and `PeselRegistryError::Io` if reading fails.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<PeselExceptionRegistry, PeselRegistryError> {
+        let mut registry = PeselExceptionRegistry::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let number = line.trim();
+            if !number.is_empty() && !number.starts_with('#') {
+                registry.insert(number).map_err(|error| PeselRegistryError::InvalidEntry { line_number: index + 1, error })?;
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Reads registry from file (see `PeselExceptionRegistry::from_reader` for the format and errors). Requires `std` feature.
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<PeselExceptionRegistry, PeselRegistryError> {
+        PeselExceptionRegistry::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Adds number to the registry. Returns `false` if it was already there, and `PeselError` if it is not a PESEL number.
+    pub fn insert(&mut self, pesel_number: &str) -> Result<bool, PeselError> {
+        let pesel = PESEL::from_str(pesel_number)?;
+        Ok(self.numbers.insert(pesel.as_str().to_string()))
+    }
+
+    /// Checks if number is in the registry
+    pub fn contains(&self, pesel_number: &str) -> bool {
+        self.numbers.contains(pesel_number)
+    }
+
+    /// Returns number of PESELs in the registry
+    pub fn len(&self) -> usize {
+        self.numbers.len()
+    }
+
+    /// Checks if registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<HashSet<String>> for PeselExceptionRegistry {
+    type Error = PeselRegistryError;
+
+    fn try_from(numbers: HashSet<String>) -> Result<PeselExceptionRegistry, PeselRegistryError> {
+        PeselExceptionRegistry::from_numbers(numbers)
+    }
+}
+
+impl PESEL {
+    /// Returns validity status of PESEL, treating numbers from `registry` failing the checksum check as recognized exceptions
+    pub fn validity(&self, registry: &PeselExceptionRegistry) -> PeselValidity {
+        match (self.is_valid(), registry.contains(self.as_str())) {
+            (true, _) => PeselValidity::Valid,
+            (false, true) => PeselValidity::RecognizedException,
+            (false, false) => PeselValidity::InvalidChecksum,
+        }
+    }
+}
+
+#[cfg(test)]
+mod pesel_exceptions_tests {
+    use super::{PeselExceptionRegistry, PeselRegistryError, PeselValidity};
+    use crate::pesel::PESEL;
+    use crate::pesel_parsing_error::PeselError;
+    use std::str::FromStr;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn registry_should_be_read_line_by_line() {
+        let input = "# officially issued numbers\n44051401459\n\n  02270803625  \n";
+        let registry = PeselExceptionRegistry::from_reader(input.as_bytes()).unwrap();
+
+        assert_eq!(2, registry.len());
+        assert!(registry.contains("44051401459"));
+        assert!(registry.contains("02270803625"));
+        assert!(!registry.contains("# officially issued numbers"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn invalid_line_should_be_reported_with_its_number() {
+        let input = "# officially issued numbers\n44051401459\n\n4405140145a\n";
+
+        match PeselExceptionRegistry::from_reader(input.as_bytes()) {
+            Err(PeselRegistryError::InvalidEntry { line_number, error }) => {
+                assert_eq!(4, line_number);
+                assert_eq!(PeselError::BadFormat { index: 10, character: 'a' }, error);
+            },
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn invalid_entry_should_be_rejected() {
+        let mut registry = PeselExceptionRegistry::new();
+
+        assert_eq!(Ok(true), registry.insert("44051401459"));
+        assert_eq!(Ok(false), registry.insert("44051401459"));
+        assert_eq!(Err(PeselError::InvalidDoB { year: 1944, month: 5, day: 32 }), registry.insert("44053201458"));
+        assert_eq!(1, registry.len());
+        assert!(matches!(PeselExceptionRegistry::from_numbers(vec!["44051401459", "440514"]),
+            Err(PeselRegistryError::InvalidEntry { line_number: 2, error: PeselError::SizeError { expected: 11, found: 6 } })));
+    }
+
+    #[test]
+    fn validity_should_recognize_exceptions() {
+        let registry = PeselExceptionRegistry::from_numbers(vec!["44051401459"]).unwrap();
+
+        assert_eq!(PeselValidity::Valid, PESEL::from_str("44051401458").unwrap().validity(&registry));
+        assert_eq!(PeselValidity::RecognizedException, PESEL::from_str("44051401459").unwrap().validity(&registry));
+        assert_eq!(PeselValidity::InvalidChecksum, PESEL::from_str("44051401457").unwrap().validity(&registry));
+        assert!(!PeselValidity::InvalidChecksum.is_accepted());
+        assert!(PeselValidity::RecognizedException.is_accepted());
+    }
+}
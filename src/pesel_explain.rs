@@ -0,0 +1,151 @@
+use crate::pesel::{PESEL, CHECKSUM_WEIGHTS};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Single step of checksum calculation: digit multiplied by its weight
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChecksumStep {
+    pub index:      usize,      // position of the digit in PESEL (counting from 0)
+    pub digit:      u8,         // value of the digit
+    pub weight:     u8,         // weight of the position (9, 7, 3, 1, 9, 7, 3, 1, 9, 7)
+    pub product:    u8,         // digit * weight
+}
+
+/// How century of birth is decoded from month encoded in PESEL
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CenturyMapping {
+    pub encoded_year:   u8,     // digits 1-2: year of birth within century
+    pub encoded_month:  u8,     // digits 3-4: month of birth with century offset
+    pub month_offset:   u8,     // 80 for 1800s, 0 for 1900s, 20 for 2000s, 40 for 2100s, 60 for 2200s
+    pub century:        u16,    // first year of the century the offset stands for
+    pub birth_year:     u16,    // century + encoded year
+    pub birth_month:    u8,     // encoded month - offset
+    pub birth_day:      u8,     // digits 5-6
+}
+
+/// Step by step explanation of PESEL validation (see `PESEL::explain`).
+///
+/// `Display` renders it as human readable text; with `serde` feature enabled it can be serialized (i.e. to JSON) as well.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PeselExplanation {
+    pub pesel:                  String,             // explained PESEL number
+    pub steps:                  Vec<ChecksumStep>,  // checksum calculation for the first ten digits
+    pub sum:                    u16,                // sum of all the products
+    pub expected_check_digit:   u8,                 // sum modulo 10
+    pub check_digit:            u8,                 // the last digit of PESEL
+    pub is_valid:               bool,               // true if check digit matches the expected one
+    pub century:                CenturyMapping,     // decoding of date of birth
+}
+
+impl PESEL {
+    /// Explains validation of PESEL step by step: how checksum is calculated and compared with the check digit,
+    /// and how date of birth (with century) is decoded. Handy when somebody asks why a number is "invalid".
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    /// use std::str::FromStr;
+    ///
+    /// let explanation = PESEL::from_str("44051401459").unwrap().explain();
+    /// assert_eq!((8, 9), (explanation.expected_check_digit, explanation.check_digit));
+    /// assert_eq!(36, explanation.steps[0].product);
+    /// println!("{}", explanation);
+    /// ```
+    pub fn explain(&self) -> PeselExplanation {
+        let steps: Vec<ChecksumStep> = self.as_str().bytes().zip(CHECKSUM_WEIGHTS.iter()).enumerate()
+            .map(|(index, (digit, weight))| ChecksumStep {
+                index,
+                digit: digit - b'0',
+                weight: *weight,
+                product: (digit - b'0') * weight,
+            })
+            .collect();
+        let sum = steps.iter().map(|step| step.product as u16).sum::<u16>();
+        let components = self.components();
+
+        PeselExplanation {
+            pesel: self.as_str().to_string(),
+            steps,
+            sum,
+            expected_check_digit: (sum % 10) as u8,
+            check_digit: components.check_digit(),
+            is_valid: self.is_valid(),
+            century: CenturyMapping {
+                encoded_year: components.encoded_year(),
+                encoded_month: components.encoded_month(),
+                month_offset: components.encoded_month() - components.birth_month(),
+                century: components.century(),
+                birth_year: components.birth_year(),
+                birth_month: components.birth_month(),
+                birth_day: components.day(),
+            },
+        }
+    }
+}
+
+impl core::fmt::Display for PeselExplanation {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        writeln!(f, "PESEL: {}", self.pesel)?;
+        writeln!(f, "position  digit  weight  product")?;
+        for step in &self.steps {
+            writeln!(f, "{:>8}  {:>5}  {:>6}  {:>7}", step.index + 1, step.digit, step.weight, step.product)?;
+        }
+        writeln!(f, "sum of products: {}", self.sum)?;
+        let status = match self.is_valid {
+            true => "valid",
+            false => "invalid",
+        };
+        writeln!(f, "{} mod 10 = {}, check digit: {} - checksum {}", self.sum, self.expected_check_digit, self.check_digit, status)?;
+        let century = &self.century;
+        write!(f, "month {:02} = month {} + offset {} (century {}), date of birth: {:04}-{:02}-{:02}", century.encoded_month,
+            century.birth_month, century.month_offset, century.century, century.birth_year, century.birth_month, century.birth_day)
+    }
+}
+
+#[cfg(test)]
+mod pesel_explain_tests {
+    use crate::pesel::PESEL;
+    use std::str::FromStr;
+
+    #[test]
+    fn checksum_calculation_should_be_explained() {
+        let explanation = PESEL::from_str("44051401459").unwrap().explain();
+
+        assert_eq!(vec![36, 28, 0, 5, 9, 28, 0, 1, 36, 35], explanation.steps.iter().map(|step| step.product).collect::<Vec<_>>());
+        assert_eq!(178, explanation.sum);
+        assert_eq!(8, explanation.expected_check_digit);
+        assert_eq!(9, explanation.check_digit);
+        assert!(!explanation.is_valid);
+    }
+
+    #[test]
+    fn century_mapping_should_be_explained() {
+        let century = PESEL::from_str("02270803624").unwrap().explain().century;
+
+        assert_eq!((27, 20, 2000), (century.encoded_month, century.month_offset, century.century));
+        assert_eq!((2002, 7, 8), (century.birth_year, century.birth_month, century.birth_day));
+    }
+
+    #[test]
+    fn explanation_should_be_rendered_as_text() {
+        let text = PESEL::from_str("44051401459").unwrap().explain().to_string();
+
+        assert!(text.contains("       1      4       9       36\n"));
+        assert!(text.contains("178 mod 10 = 8, check digit: 9 - checksum invalid\n"));
+        assert!(text.ends_with("month 05 = month 5 + offset 0 (century 1900), date of birth: 1944-05-14"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn explanation_should_be_serialized_to_json() {
+        let json = serde_json::to_value(PESEL::from_str("02270803624").unwrap().explain()).unwrap();
+
+        assert_eq!(serde_json::json!({"index": 0, "digit": 0, "weight": 9, "product": 0}), json["steps"][0]);
+        assert_eq!(4, json["expected_check_digit"]);
+        assert_eq!(true, json["is_valid"]);
+        assert_eq!(20, json["century"]["month_offset"]);
+    }
+}
@@ -0,0 +1,212 @@
+//! Fast validation of PESEL numbers stored as raw bytes, i.e. many fixed-width records in one buffer.
+//!
+//! Records are checked exactly the same way as by `PESEL::from_str_strict` (format, date of birth, checksum), but nothing is allocated
+//! and only kind of the error is reported. On x86_64 checksum is calculated with SSSE3 instructions when CPU supports them
+//! (detected at runtime with `std` feature, at compile time otherwise), with scalar fallback everywhere else.
+//!
+//! Example:
+//! ```rust
+//! use pesel::pesel_fast;
+//! use pesel::pesel_parsing_error::PeselErrorKind;
+//!
+//! let buffer = b"44051401458\n44051401459\n4405140145a\n";
+//! let results: Vec<_> = pesel_fast::validate_fixed_width(buffer, 12).collect();
+//! assert_eq!(vec![Ok(()), Err(PeselErrorKind::InvalidChecksum), Err(PeselErrorKind::BadFormat)], results);
+//! ```
+use crate::pesel::{PESEL, CHECKSUM_WEIGHTS};
+use crate::pesel_parsing_error::PeselErrorKind;
+use core::convert::TryInto;
+
+/// Length of a single PESEL record in bytes
+pub const RECORD_LENGTH: usize = 11;
+
+/// Validates a single record (11 ASCII digits)
+pub fn validate_record(record: &[u8]) -> Result<(), PeselErrorKind> {
+    validate_with(record, detect_checker())
+}
+
+/// Validates records laid out every `stride` bytes in `buffer` (i.e. stride 12 for records separated with `\n`, 11 for no separators).
+/// Bytes between records are ignored. Trailing bytes too short to hold a record are reported as `PeselErrorKind::SizeError`.
+///
+/// Panics if `stride` is shorter than `RECORD_LENGTH`.
+pub fn validate_fixed_width(buffer: &[u8], stride: usize) -> FixedWidthRecords<'_> {
+    FixedWidthRecords::new(buffer, stride, detect_checker())
+}
+
+/// Same as `validate_fixed_width`, but always uses portable scalar implementation
+pub fn validate_fixed_width_scalar(buffer: &[u8], stride: usize) -> FixedWidthRecords<'_> {
+    FixedWidthRecords::new(buffer, stride, check_digits_scalar)
+}
+
+/// Returns number of valid records in `buffer` (see `validate_fixed_width`)
+pub fn count_valid(buffer: &[u8], stride: usize) -> usize {
+    validate_fixed_width(buffer, stride).filter(|result| result.is_ok()).count()
+}
+
+/// Checks that record consists of digits only and that its checksum is correct
+type Checker = fn(&[u8; RECORD_LENGTH]) -> Result<(), PeselErrorKind>;
+
+/// Iterator over validation results of fixed-width records (see `validate_fixed_width`)
+#[derive(Clone)]
+pub struct FixedWidthRecords<'a> {
+    buffer:     &'a [u8],       // records not yet validated
+    stride:     usize,          // distance between beginnings of two consecutive records
+    checker:    Checker,        // SIMD or scalar implementation
+}
+
+impl<'a> FixedWidthRecords<'a> {
+    fn new(buffer: &'a [u8], stride: usize, checker: Checker) -> FixedWidthRecords<'a> {
+        assert!(stride >= RECORD_LENGTH, "stride has to be at least {} bytes", RECORD_LENGTH);
+        FixedWidthRecords { buffer, stride, checker }
+    }
+}
+
+impl<'a> Iterator for FixedWidthRecords<'a> {
+    type Item = Result<(), PeselErrorKind>;
+
+    fn next(&mut self) -> Option<Result<(), PeselErrorKind>> {
+        if self.buffer.is_empty() {
+            return None;
+        }
+        let record = &self.buffer[..RECORD_LENGTH.min(self.buffer.len())];
+        self.buffer = &self.buffer[self.stride.min(self.buffer.len())..];
+        Some(validate_with(record, self.checker))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let records = (self.buffer.len() + self.stride - 1) / self.stride;
+        (records, Some(records))
+    }
+}
+
+impl<'a> ExactSizeIterator for FixedWidthRecords<'a> {}
+
+/// Utility function - validates record with given checker. Checks are performed in the same order as by `PESEL::from_str`.
+fn validate_with(record: &[u8], checker: Checker) -> Result<(), PeselErrorKind> {
+    let record: &[u8; RECORD_LENGTH] = match record.len() {
+        RECORD_LENGTH => record.try_into().expect("length has been checked"),
+        _ => return Err(PeselErrorKind::SizeError),
+    };
+    let digits_and_checksum = checker(record);
+    if digits_and_checksum == Err(PeselErrorKind::BadFormat) {
+        return digits_and_checksum;
+    }
+    let number = |index: usize| (record[index] - b'0') * 10 + record[index + 1] - b'0';
+    if let Err(error) = PESEL::check_encoded_date(number(0), number(2), number(4)) {
+        return Err(error.kind());
+    }
+    digits_and_checksum
+}
+
+/// Utility function - returns the fastest checker supported by the CPU
+fn detect_checker() -> Checker {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    {
+        if std::is_x86_feature_detected!("ssse3") {
+            return check_digits_ssse3;
+        }
+    }
+    #[cfg(all(target_arch = "x86_64", not(feature = "std"), target_feature = "ssse3"))]
+    {
+        return check_digits_ssse3;
+    }
+    #[allow(unreachable_code)]
+    check_digits_scalar
+}
+
+/// Utility function - portable, table-driven checker
+fn check_digits_scalar(record: &[u8; RECORD_LENGTH]) -> Result<(), PeselErrorKind> {
+    if !record.iter().all(u8::is_ascii_digit) {
+        return Err(PeselErrorKind::BadFormat);
+    }
+    let sum: u32 = record.iter().zip(CHECKSUM_WEIGHTS.iter()).map(|(digit, weight)| (digit - b'0') as u32 * *weight as u32).sum();
+    match (sum % 10) as u8 == record[RECORD_LENGTH - 1] - b'0' {
+        true => Ok(()),
+        false => Err(PeselErrorKind::InvalidChecksum),
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", any(feature = "std", target_feature = "ssse3")))]
+fn check_digits_ssse3(record: &[u8; RECORD_LENGTH]) -> Result<(), PeselErrorKind> {
+    // only selected by `detect_checker` when CPU supports SSSE3
+    unsafe { ssse3::check_digits(record) }
+}
+
+#[cfg(all(target_arch = "x86_64", any(feature = "std", target_feature = "ssse3")))]
+mod ssse3 {
+    use super::RECORD_LENGTH;
+    use crate::pesel_parsing_error::PeselErrorKind;
+    use core::arch::x86_64::*;
+
+    /// Checks all digits at once: check digit gets weight -1, so the weighted sum of a valid PESEL is divisible by 10
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn check_digits(record: &[u8; RECORD_LENGTH]) -> Result<(), PeselErrorKind> {
+        let mut padded = [b'0'; 16];
+        padded[..RECORD_LENGTH].copy_from_slice(record);
+        let digits = _mm_sub_epi8(_mm_loadu_si128(padded.as_ptr() as *const __m128i), _mm_set1_epi8(b'0' as i8));
+
+        // bytes other than '0'..='9' wrap around to values greater than 9
+        let nine = _mm_set1_epi8(9);
+        let in_range = _mm_cmpeq_epi8(_mm_max_epu8(digits, nine), nine);
+        if _mm_movemask_epi8(in_range) != 0xFFFF {
+            return Err(PeselErrorKind::BadFormat);
+        }
+
+        let weights = _mm_setr_epi8(9, 7, 3, 1, 9, 7, 3, 1, 9, 7, -1, 0, 0, 0, 0, 0);
+        let pairs = _mm_maddubs_epi16(digits, weights);
+        let quads = _mm_madd_epi16(pairs, _mm_set1_epi16(1));
+        let halves = _mm_add_epi32(quads, _mm_shuffle_epi32(quads, 0b01_00_11_10));
+        let total = _mm_cvtsi128_si32(_mm_add_epi32(halves, _mm_shuffle_epi32(halves, 0b10_11_00_01)));
+        match total.rem_euclid(10) {
+            0 => Ok(()),
+            _ => Err(PeselErrorKind::InvalidChecksum),
+        }
+    }
+}
+
+#[cfg(test)]
+mod pesel_fast_tests {
+    use super::{count_valid, validate_fixed_width, validate_fixed_width_scalar, validate_record, RECORD_LENGTH};
+    use crate::pesel::PESEL;
+    use crate::pesel_parsing_error::PeselErrorKind;
+
+    const RECORDS: [&str; 10] = ["44051401458", "44051401459", "4405140145a", "44053201458", "44951201458", "02270803624",
+        "83022998790", "99999999999", "00000000000", "/0270803624"];
+
+    #[test]
+    fn results_should_match_strict_parsing() {
+        for record in RECORDS.iter() {
+            let expected = PESEL::from_str_strict(record).map(|_| ()).map_err(|e| e.kind());
+            assert_eq!(expected, validate_record(record.as_bytes()), "{}", record);
+        }
+    }
+
+    #[test]
+    fn simd_and_scalar_implementations_should_agree() {
+        let mut buffer = Vec::new();
+        for record in RECORDS.iter() {
+            buffer.extend_from_slice(record.as_bytes());
+        }
+        // every ordinal for a single date: once with correct checksum, once with wrong one
+        for ordinal in 0..500 {
+            let number = format!("440514{:04}", ordinal);
+            let checksum = PESEL::calc_checksum_from_pesel_string(&number);
+            buffer.extend_from_slice(format!("{}{}", number, checksum).as_bytes());
+            buffer.extend_from_slice(format!("{}{}", number, (checksum + 1) % 10).as_bytes());
+        }
+
+        let fast: Vec<_> = validate_fixed_width(&buffer, RECORD_LENGTH).collect();
+        let scalar: Vec<_> = validate_fixed_width_scalar(&buffer, RECORD_LENGTH).collect();
+        assert_eq!(1010, fast.len());
+        assert_eq!(scalar, fast);
+        assert_eq!(502, count_valid(&buffer, RECORD_LENGTH));
+    }
+
+    #[test]
+    fn trailing_bytes_should_be_reported_as_size_error() {
+        let results: Vec<_> = validate_fixed_width(b"44051401458\r\n440514", 13).collect();
+
+        assert_eq!(vec![Ok(()), Err(PeselErrorKind::SizeError)], results);
+        assert_eq!(Err(PeselErrorKind::SizeError), validate_record(b"440514014580"));
+    }
+}
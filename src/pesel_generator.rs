@@ -0,0 +1,232 @@
+use crate::pesel::{PESEL, PeselGender};
+use crate::pesel_enumeration::PESELS_PER_DAY_AND_GENDER;
+use crate::pesel_parsing_error::PeselError;
+use chrono::{Datelike, Duration, NaiveDate};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+
+/// Generator of random, unique PESEL numbers, i.e. for building synthetic test populations.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_generator::PeselGenerator;
+/// use chrono::NaiveDate;
+///
+/// let generator = PeselGenerator::new(NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(), NaiveDate::from_ymd_opt(2000, 12, 31).unwrap()).unwrap()
+///     .female_ratio(0.52)
+///     .seed(2019);
+/// let pesels: Vec<_> = generator.unique(1000).unwrap().collect();
+/// assert_eq!(1000, pesels.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PeselGenerator {
+    first:          NaiveDate,      // earliest birth date (inclusive)
+    last:           NaiveDate,      // latest birth date (inclusive)
+    female_ratio:   f64,            // fraction of generated PESELs belonging to women
+    seed:           Option<u64>,    // seed for reproducible output, random if not set
+}
+
+impl PeselGenerator {
+    /// Creates generator for birth dates between `first` and `last` (both inclusive). By default half of generated PESELs belong to women,
+    /// and output is different on every run.
+    ///
+    /// Returns `PeselError::DoBOutOfRange` if any of the dates is out of PESEL range (1800-2299).
+    pub fn new(first: NaiveDate, last: NaiveDate) -> Result<PeselGenerator, PeselError> {
+        if let Some(year) = [first.year(), last.year()].iter().copied().find(|year| ! PESEL::is_date_in_range(*year)) {
+            return Err(PeselError::DoBOutOfRange { year });
+        }
+        Ok(PeselGenerator {
+            first,
+            last,
+            female_ratio: 0.5,
+            seed: None,
+        })
+    }
+
+    /// Sets fraction (0.0 - 1.0) of generated PESELs belonging to women. Values out of range are clamped,
+    /// NaN or infinity makes `unique` return `PeselError::InvalidRatio`.
+    pub fn female_ratio(mut self, ratio: f64) -> PeselGenerator {
+        self.female_ratio = ratio;
+        self
+    }
+
+    /// Makes output reproducible: generator with the same settings and seed always yields the same sequence
+    /// (for a given version of `rand` crate)
+    pub fn seed(mut self, seed: u64) -> PeselGenerator {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns number of distinct PESEL numbers available for a single gender within the range of birth dates
+    pub fn capacity_per_gender(&self) -> usize {
+        self.days() * PESELS_PER_DAY_AND_GENDER
+    }
+
+    /// Returns lazy iterator yielding exactly `count` distinct PESEL numbers, with birth dates spread uniformly over the range.
+    /// Number of women is `count * female_ratio` (rounded), the rest are men.
+    ///
+    /// Returns `PeselError::NotEnoughNumbers` if there are not enough distinct PESEL numbers for either gender within the range of dates,
+    /// and `PeselError::InvalidRatio` if female ratio is not a finite number.
+    ///
+    /// Note: all numbers already generated are remembered, so memory usage grows with `count`. Generation slows down when `count`
+    /// gets close to the capacity of the range (new numbers are drawn until an unused one is found).
+    pub fn unique(&self, count: usize) -> Result<UniquePesels, PeselError> {
+        if !self.female_ratio.is_finite() {
+            return Err(PeselError::InvalidRatio);
+        }
+        let females = (count as f64 * self.female_ratio.clamp(0.0, 1.0)).round() as usize;
+        let males = count - females;
+        let capacity = self.capacity_per_gender();
+        if females > capacity || males > capacity {
+            return Err(PeselError::NotEnoughNumbers);
+        }
+
+        Ok(UniquePesels {
+            rng: match self.seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            },
+            first: self.first,
+            days: self.days(),
+            females_left: females,
+            males_left: males,
+            used: HashSet::with_capacity(count),
+        })
+    }
+
+    /// Utility function - number of days in the range
+    fn days(&self) -> usize {
+        match self.first <= self.last {
+            true => (self.last - self.first).num_days() as usize + 1,
+            false => 0,
+        }
+    }
+}
+
+/// Iterator over distinct random PESEL numbers (see `PeselGenerator::unique`)
+#[derive(Debug)]
+pub struct UniquePesels {
+    rng:            StdRng,
+    first:          NaiveDate,
+    days:           usize,
+    females_left:   usize,
+    males_left:     usize,
+    used:           HashSet<u64>,   // (day offset, ordinal) pairs already used
+}
+
+impl Iterator for UniquePesels {
+    type Item = PESEL;
+
+    fn next(&mut self) -> Option<PESEL> {
+        let left = self.females_left + self.males_left;
+        if left == 0 {
+            return None;
+        }
+        // drawing gender proportionally to what is left guarantees exact number of women and men
+        let gender = match self.rng.gen_range(0, left) < self.females_left {
+            true => PeselGender::Female,
+            false => PeselGender::Male,
+        };
+        let gender_offset = match gender {
+            PeselGender::Female => 0,
+            PeselGender::Male => 1,
+        };
+
+        loop {
+            let day = self.rng.gen_range(0, self.days);
+            let ordinal = self.rng.gen_range(0, PESELS_PER_DAY_AND_GENDER) as u16 * 2 + gender_offset;
+            if !self.used.insert(day as u64 * 10000 + ordinal as u64) {
+                continue;
+            }
+
+            match gender {
+                PeselGender::Female => self.females_left -= 1,
+                PeselGender::Male => self.males_left -= 1,
+            }
+            let date = self.first + Duration::days(day as i64);
+            let pesel = PESEL::with_ordinal(date.year() as u16, date.month() as u8, date.day() as u8, ordinal, gender)
+                .expect("date range has been validated when generator was created");
+            return Some(pesel);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.females_left + self.males_left;
+        (left, Some(left))
+    }
+}
+
+impl ExactSizeIterator for UniquePesels {}
+
+#[cfg(test)]
+mod pesel_generator_tests {
+    use super::PeselGenerator;
+    use crate::pesel::PeselGender;
+    use crate::pesel_parsing_error::PeselError;
+    use chrono::NaiveDate;
+    use std::collections::HashSet;
+
+    fn generator(first: (i32, u32, u32), last: (i32, u32, u32)) -> PeselGenerator {
+        PeselGenerator::new(NaiveDate::from_ymd_opt(first.0, first.1, first.2).unwrap(), NaiveDate::from_ymd_opt(last.0, last.1, last.2).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn generated_pesels_should_be_unique_and_valid() {
+        let pesels: Vec<_> = generator((2000, 1, 1), (2000, 1, 1)).seed(1).unique(9000).unwrap().collect();
+        let distinct: HashSet<String> = pesels.iter().map(|p| p.pesel_number()).collect();
+
+        assert_eq!(9000, pesels.len());
+        assert_eq!(9000, distinct.len());
+        assert!(pesels.iter().all(|p| p.is_valid() && p.pesel_number().starts_with("002101")));
+    }
+
+    #[test]
+    fn gender_ratio_should_be_respected() {
+        let pesels: Vec<_> = generator((1980, 1, 1), (1989, 12, 31)).female_ratio(0.3).unique(1000).unwrap().collect();
+        let females = pesels.iter().filter(|p| p.gender() == PeselGender::Female).count();
+
+        assert_eq!(300, females);
+    }
+
+    #[test]
+    fn non_finite_gender_ratio_should_result_in_error() {
+        assert_eq!(PeselError::InvalidRatio, generator((1980, 1, 1), (1989, 12, 31)).female_ratio(f64::NAN).unique(10).unwrap_err());
+        assert_eq!(PeselError::InvalidRatio, generator((1980, 1, 1), (1989, 12, 31)).female_ratio(f64::INFINITY).unique(10).unwrap_err());
+        assert_eq!(10, generator((1980, 1, 1), (1989, 12, 31)).female_ratio(1.5).unique(10).unwrap()
+            .filter(|p| p.gender() == PeselGender::Female).count());
+    }
+
+    #[test]
+    fn birth_dates_should_be_within_range() {
+        let pesels: Vec<_> = generator((1999, 12, 31), (2000, 1, 1)).unique(100).unwrap().collect();
+
+        assert!(pesels.iter().all(|p| p.pesel_number().starts_with("991231") || p.pesel_number().starts_with("002101")));
+    }
+
+    #[test]
+    fn the_same_seed_should_give_the_same_pesels() {
+        let generator = generator((1950, 1, 1), (2000, 12, 31)).seed(42);
+        let first: Vec<String> = generator.unique(100).unwrap().map(|p| p.pesel_number()).collect();
+        let second: Vec<String> = generator.unique(100).unwrap().map(|p| p.pesel_number()).collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn requesting_more_pesels_than_available_should_result_in_error() {
+        let generator = generator((2000, 1, 1), (2000, 1, 2));
+
+        assert_eq!(10000, generator.capacity_per_gender());
+        assert!(generator.unique(20000).is_ok());
+        assert_eq!(PeselError::NotEnoughNumbers, generator.unique(20001).unwrap_err());
+        assert_eq!(PeselError::NotEnoughNumbers, generator.clone().female_ratio(1.0).unique(10001).unwrap_err());
+    }
+
+    #[test]
+    fn dates_out_of_range_should_result_in_error() {
+        let result = PeselGenerator::new(NaiveDate::from_ymd_opt(1799, 1, 1).unwrap(), NaiveDate::from_ymd_opt(1800, 1, 1).unwrap());
+
+        assert_eq!(PeselError::DoBOutOfRange { year: 1799 }, result.unwrap_err());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::pesel::PESEL;
+use crate::pesel_compact::CompactPesel;
+use crate::pesel_parsing_error::{PeselError, PeselErrorKind};
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::iter::{FilterMap, Map};
+use core::str::FromStr;
+
+/// Iterator returned by `PeselIteratorExt::parse_pesels`
+pub type ParsePesels<I> = Map<I, fn(<I as Iterator>::Item) -> Result<PESEL, PeselError>>;
+
+/// Iterator returned by `PeselIteratorExt::valid_pesels_only`
+pub type ValidPesels<I> = FilterMap<I, fn(<I as Iterator>::Item) -> Option<PESEL>>;
+
+/// Inputs split by `PeselIteratorExt::partition_by_validity`
+#[derive(Debug, Default)]
+pub struct PeselPartition {
+    pub valid:              Vec<PESEL>,                     // parsed, with a correct checksum
+    pub invalid_checksum:   Vec<PESEL>,                     // parsed, but failing checksum check
+    pub errors:             Vec<(String, PeselError)>,      // inputs which could not be parsed, along with the reason
+}
+
+impl PeselPartition {
+    /// Returns number of inputs which failed to parse because of given error kind
+    pub fn error_count(&self, kind: PeselErrorKind) -> usize {
+        self.errors.iter().filter(|(_, error)| error.kind() == kind).count()
+    }
+}
+
+/// Iterator returned by `PeselIteratorExt::dedup_pesels`
+pub struct DedupPesels<I> {
+    inner:  I,                          // parsing results
+    seen:   BTreeSet<CompactPesel>,     // PESELs returned so far
+}
+
+impl<I> Iterator for DedupPesels<I> where I: Iterator<Item = Result<PESEL, PeselError>> {
+    type Item = Result<PESEL, PeselError>;
+
+    fn next(&mut self) -> Option<Result<PESEL, PeselError>> {
+        loop {
+            match self.inner.next()? {
+                Ok(pesel) if !self.seen.insert(CompactPesel::from(&pesel)) => continue,
+                result => return Some(result),
+            }
+        }
+    }
+}
+
+/// Adapters validating sequences of strings, available for every iterator over `AsRef<str>` items.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_iter::PeselIteratorExt;
+///
+/// let input = vec!["44051401458", "44051401459", "4405140145a", "44051401458"];
+/// let valid: Vec<_> = input.iter().valid_pesels_only().collect();
+/// assert_eq!(2, valid.len());
+///
+/// let partition = input.iter().partition_by_validity();
+/// assert_eq!((2, 1, 1), (partition.valid.len(), partition.invalid_checksum.len(), partition.errors.len()));
+///
+/// assert_eq!(3, input.iter().dedup_pesels().count());
+/// ```
+pub trait PeselIteratorExt: Iterator + Sized where Self::Item: AsRef<str> {
+    /// Parses every string with `PESEL::from_str`
+    fn parse_pesels(self) -> ParsePesels<Self> {
+        self.map(|s| PESEL::from_str(s.as_ref()))
+    }
+
+    /// Returns only PESELs which have been parsed and pass the checksum check (see `PESEL::is_valid`), silently skipping everything else
+    fn valid_pesels_only(self) -> ValidPesels<Self> {
+        self.filter_map(|s| PESEL::from_str(s.as_ref()).ok().filter(|pesel| pesel.is_valid()))
+    }
+
+    /// Parses all strings, splitting them into valid PESELs, PESELs failing the checksum check and inputs which could not be parsed
+    fn partition_by_validity(self) -> PeselPartition {
+        let mut partition = PeselPartition::default();
+        for s in self {
+            match PESEL::from_str(s.as_ref()) {
+                Ok(pesel) if pesel.is_valid() => partition.valid.push(pesel),
+                Ok(pesel) => partition.invalid_checksum.push(pesel),
+                Err(error) => partition.errors.push((s.as_ref().to_string(), error)),
+            }
+        }
+        partition
+    }
+
+    /// Parses every string with `PESEL::from_str`, skipping PESELs which have already been returned (errors are always returned)
+    fn dedup_pesels(self) -> DedupPesels<ParsePesels<Self>> {
+        DedupPesels {
+            inner: self.parse_pesels(),
+            seen: BTreeSet::new(),
+        }
+    }
+}
+
+impl<I> PeselIteratorExt for I where I: Iterator, I::Item: AsRef<str> {}
+
+#[cfg(test)]
+mod pesel_iter_tests {
+    use super::PeselIteratorExt;
+    use crate::pesel_parsing_error::{PeselError, PeselErrorKind};
+
+    const INPUT: [&str; 7] = ["44051401458", "44051401459", "4405140145a", "440514", "44053201458", "02270803624", "44051401458"];
+
+    #[test]
+    fn strings_should_be_parsed_in_order() {
+        let results: Vec<_> = INPUT.iter().parse_pesels().collect();
+
+        assert_eq!(7, results.len());
+        assert_eq!("02270803624", results[5].as_ref().unwrap().as_str());
+        assert_eq!(PeselError::SizeError { expected: 11, found: 6 }, *results[3].as_ref().unwrap_err());
+    }
+
+    #[test]
+    fn only_valid_pesels_should_be_returned() {
+        let valid: Vec<String> = INPUT.iter().valid_pesels_only().map(|pesel| pesel.pesel_number()).collect();
+
+        assert_eq!(vec!["44051401458", "02270803624", "44051401458"], valid);
+    }
+
+    #[test]
+    fn inputs_should_be_partitioned_by_validity() {
+        let partition = INPUT.iter().map(|s| s.to_string()).partition_by_validity();
+
+        assert_eq!(3, partition.valid.len());
+        assert_eq!("44051401459", partition.invalid_checksum[0].as_str());
+        assert_eq!(("4405140145a".to_string(), PeselError::BadFormat { index: 10, character: 'a' }), partition.errors[0]);
+        assert_eq!(1, partition.error_count(PeselErrorKind::InvalidDoB));
+        assert_eq!(0, partition.error_count(PeselErrorKind::DoBOutOfRange));
+    }
+
+    #[test]
+    fn duplicates_should_be_skipped() {
+        let results: Vec<_> = INPUT.iter().chain(INPUT.iter()).dedup_pesels().collect();
+
+        assert_eq!(3, results.iter().filter(|result| result.is_ok()).count());
+        assert_eq!(6, results.iter().filter(|result| result.is_err()).count());
+    }
+}
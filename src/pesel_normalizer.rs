@@ -0,0 +1,237 @@
+use crate::pesel::PESEL;
+use crate::pesel_parsing_error::PeselError;
+use crate::pesel_validator::PeselValidator;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// Zeros of Unicode decimal digit blocks recognized by `PeselNormalizer` (digits of every block are consecutive code points)
+const UNICODE_ZEROS: [char; 8] = [
+    '\u{FF10}',     // fullwidth
+    '\u{0660}',     // Arabic-Indic
+    '\u{06F0}',     // Extended Arabic-Indic (Persian)
+    '\u{0966}',     // Devanagari
+    '\u{09E6}',     // Bengali
+    '\u{1D7CE}',    // mathematical bold
+    '\u{1D7D8}',    // mathematical double-struck
+    '\u{1D7EC}',    // mathematical sans-serif bold
+];
+
+const PREFIX: &str = "PESEL";
+
+/// Single change made to the input by `PeselNormalizer`
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PeselNormalization {
+    /// Leading and/or trailing whitespace has been removed
+    Trimmed,
+    /// `PESEL` prefix (optionally followed by `:`) has been removed
+    PrefixRemoved,
+    /// Given number of separators (i.e. spaces, dashes) has been removed
+    SeparatorsRemoved(usize),
+    /// Given number of non-ASCII digits (i.e. fullwidth) has been converted to ASCII
+    DigitsConverted(usize),
+}
+
+/// PESEL parsed by `PeselNormalizer`, along with the list of changes made to the input
+#[derive(Debug)]
+pub struct NormalizedPesel {
+    pub pesel:          PESEL,                      // parsed PESEL
+    pub normalizations: Vec<PeselNormalization>,    // changes made to the input, empty if input was already normalized
+}
+
+/// Parser accepting PESEL numbers the way people tend to type or paste them: surrounded by whitespace, with `PESEL:` prefix,
+/// separated with spaces or dashes, or written with Unicode digits (i.e. fullwidth). Input is normalized first, then the usual checks are performed.
+/// Every normalization rule can be turned off.
+///
+/// Note: positions reported by `PeselError::BadFormat` refer to the normalized string.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_normalizer::{PeselNormalizer, PeselNormalization};
+///
+/// let normalized = PeselNormalizer::new().parse(" PESEL: 440514-014-58 ").unwrap();
+/// assert_eq!("44051401458", normalized.pesel.pesel_number());
+/// assert_eq!(vec![PeselNormalization::Trimmed, PeselNormalization::PrefixRemoved, PeselNormalization::SeparatorsRemoved(2)],
+///     normalized.normalizations);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PeselNormalizer {
+    trim:           bool,           // remove leading and trailing whitespace
+    strip_prefix:   bool,           // remove `PESEL` prefix (case insensitive, optionally followed by `:`)
+    separators:     Vec<char>,      // characters removed from inside the number
+    unicode_digits: bool,           // convert Unicode digits to ASCII
+}
+
+impl PeselNormalizer {
+    /// Creates normalizer with all rules enabled. Spaces (including non-breaking ones) and dashes are treated as separators.
+    pub fn new() -> PeselNormalizer {
+        PeselNormalizer {
+            trim: true,
+            strip_prefix: true,
+            separators: vec![' ', '-', '\u{00A0}'],
+            unicode_digits: true,
+        }
+    }
+
+    /// Turns removing of leading and trailing whitespace on or off
+    pub fn trim(mut self, enabled: bool) -> PeselNormalizer {
+        self.trim = enabled;
+        self
+    }
+
+    /// Turns removing of `PESEL` prefix on or off
+    pub fn strip_prefix(mut self, enabled: bool) -> PeselNormalizer {
+        self.strip_prefix = enabled;
+        self
+    }
+
+    /// Sets characters to be removed from inside the number (empty slice turns the rule off)
+    pub fn separators(mut self, separators: &[char]) -> PeselNormalizer {
+        self.separators = separators.to_vec();
+        self
+    }
+
+    /// Turns conversion of Unicode digits (fullwidth, Arabic-Indic etc.) to ASCII on or off
+    pub fn unicode_digits(mut self, enabled: bool) -> PeselNormalizer {
+        self.unicode_digits = enabled;
+        self
+    }
+
+    /// Normalizes input, returning normalized string and the list of changes made
+    pub fn normalize(&self, s: &str) -> (String, Vec<PeselNormalization>) {
+        let mut normalizations = Vec::new();
+        let mut input = s;
+
+        if self.trim && input.trim() != input {
+            input = input.trim();
+            normalizations.push(PeselNormalization::Trimmed);
+        }
+        if self.strip_prefix {
+            if let Some(rest) = PeselNormalizer::without_prefix(input) {
+                input = rest;
+                normalizations.push(PeselNormalization::PrefixRemoved);
+            }
+        }
+
+        let mut normalized = String::with_capacity(input.len());
+        let mut separators = 0;
+        let mut converted = 0;
+        for c in input.chars() {
+            if self.separators.contains(&c) {
+                separators += 1;
+                continue;
+            }
+            match PeselNormalizer::unicode_digit(c) {
+                Some(digit) if self.unicode_digits => {
+                    normalized.push(digit);
+                    converted += 1;
+                },
+                _ => normalized.push(c),
+            }
+        }
+        if separators > 0 {
+            normalizations.push(PeselNormalization::SeparatorsRemoved(separators));
+        }
+        if converted > 0 {
+            normalizations.push(PeselNormalization::DigitsConverted(converted));
+        }
+
+        (normalized, normalizations)
+    }
+
+    /// Normalizes input and parses it with `PESEL::from_str`
+    pub fn parse(&self, s: &str) -> Result<NormalizedPesel, PeselError> {
+        let (normalized, normalizations) = self.normalize(s);
+        Ok(NormalizedPesel {
+            pesel: PESEL::from_str(&normalized)?,
+            normalizations,
+        })
+    }
+
+    /// Normalizes input and validates it with given validator
+    pub fn parse_with(&self, s: &str, validator: &PeselValidator) -> Result<NormalizedPesel, PeselError> {
+        let (normalized, normalizations) = self.normalize(s);
+        Ok(NormalizedPesel {
+            pesel: validator.validate(&normalized)?,
+            normalizations,
+        })
+    }
+
+    /// Utility function - returns input without `PESEL` prefix (and `:` and whitespace following it), or `None` if there is no prefix
+    fn without_prefix(s: &str) -> Option<&str> {
+        let head = s.get(..PREFIX.len())?;
+        if !head.eq_ignore_ascii_case(PREFIX) {
+            return None;
+        }
+        let rest = s[PREFIX.len()..].trim_start();
+        Some(rest.strip_prefix(':').unwrap_or(rest).trim_start())
+    }
+
+    /// Utility function - returns ASCII digit corresponding to non-ASCII Unicode digit
+    fn unicode_digit(c: char) -> Option<char> {
+        UNICODE_ZEROS.iter()
+            .map(|zero| (c as u32).wrapping_sub(*zero as u32))
+            .find(|offset| *offset < 10)
+            .map(|offset| (b'0' + offset as u8) as char)
+    }
+}
+
+impl Default for PeselNormalizer {
+    fn default() -> PeselNormalizer {
+        PeselNormalizer::new()
+    }
+}
+
+#[cfg(test)]
+mod pesel_normalizer_tests {
+    use super::{PeselNormalization, PeselNormalizer};
+    use crate::pesel_parsing_error::PeselError;
+    use crate::pesel_validator::PeselValidator;
+
+    #[test]
+    fn separators_and_whitespace_should_be_removed() {
+        let normalized = PeselNormalizer::new().parse("\t440 514 014-58\n").unwrap();
+
+        assert_eq!("44051401458", normalized.pesel.pesel_number());
+        assert_eq!(vec![PeselNormalization::Trimmed, PeselNormalization::SeparatorsRemoved(3)], normalized.normalizations);
+    }
+
+    #[test]
+    fn prefix_should_be_removed() {
+        assert_eq!("44051401458", PeselNormalizer::new().parse("PESEL: 44051401458").unwrap().pesel.pesel_number());
+        assert_eq!("44051401458", PeselNormalizer::new().parse("pesel 44051401458").unwrap().pesel.pesel_number());
+        assert_eq!("44051401458", PeselNormalizer::new().parse("Pesel:44051401458").unwrap().pesel.pesel_number());
+    }
+
+    #[test]
+    fn unicode_digits_should_be_converted() {
+        let normalized = PeselNormalizer::new().parse("４４０５１４０１４５８").unwrap();
+        assert_eq!("44051401458", normalized.pesel.pesel_number());
+        assert_eq!(vec![PeselNormalization::DigitsConverted(11)], normalized.normalizations);
+
+        assert_eq!("44051401458", PeselNormalizer::new().parse("٤٤٠٥١٤٠١٤٥٨").unwrap().pesel.pesel_number());
+    }
+
+    #[test]
+    fn normalized_input_should_not_be_reported_as_changed() {
+        assert!(PeselNormalizer::new().parse("44051401458").unwrap().normalizations.is_empty());
+    }
+
+    #[test]
+    fn disabled_rules_should_not_be_applied() {
+        let normalizer = PeselNormalizer::new().trim(false).strip_prefix(false).separators(&[]).unicode_digits(false);
+
+        assert_eq!(PeselError::SizeError { expected: 11, found: 12 }, normalizer.parse(" 44051401458").unwrap_err());
+        assert_eq!(PeselError::BadFormat { index: 6, character: '-' }, normalizer.parse("440514-1458").unwrap_err());
+        assert_eq!(PeselError::SizeError { expected: 11, found: 16 }, normalizer.parse("PESEL44051401458").unwrap_err());
+        assert_eq!(PeselError::SizeError { expected: 11, found: 33 }, normalizer.parse("４４０５１４０１４５８").unwrap_err());
+    }
+
+    #[test]
+    fn normalized_input_should_be_checked_by_validator() {
+        let result = PeselNormalizer::new().parse_with("440514 01459", &PeselValidator::new());
+
+        assert_eq!(PeselError::InvalidChecksum { expected: 8, found: 9 }, result.unwrap_err());
+    }
+}
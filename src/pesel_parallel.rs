@@ -0,0 +1,214 @@
+//! Parallel validation of large numbers of PESELs (requires `rayon` feature).
+//!
+//! Results are always returned in input order. For inputs not fitting in memory use `PESEL::par_from_reader`,
+//! which reads and validates the input chunk by chunk.
+use crate::pesel::PESEL;
+use crate::pesel_batch::{read_line, PeselBatchRecord, PeselBatchSummary};
+use crate::pesel_parsing_error::PeselError;
+use rayon::iter::{Map, ParallelIterator};
+use rayon::prelude::*;
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// Number of lines validated at once by `PESEL::par_summarize_reader`
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Parallel iterator returned by `ParallelPeselIterator::parse_pesels`
+pub type ParsePesels<I> = Map<I, fn(<I as ParallelIterator>::Item) -> Result<PESEL, PeselError>>;
+
+/// Parallel validation of any `rayon` parallel iterator over strings.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_parallel::ParallelPeselIterator;
+/// use rayon::prelude::*;
+///
+/// let input = vec!["44051401458", "44051401459", "4405140145a"];
+/// let results: Vec<_> = input.par_iter().parse_pesels().collect();
+/// assert!(results[0].as_ref().unwrap().is_valid());
+/// assert!(results[2].is_err());
+///
+/// let summary = input.par_iter().summarize_pesels();
+/// assert_eq!((3, 1, 1), (summary.total, summary.valid, summary.invalid_checksum));
+/// ```
+pub trait ParallelPeselIterator: ParallelIterator + Sized where Self::Item: AsRef<str> {
+    /// Parses every string with `PESEL::from_str`. For indexed iterators (i.e. over slices and vectors) order of results matches the input.
+    fn parse_pesels(self) -> ParsePesels<Self> {
+        self.map(|s| PESEL::from_str(s.as_ref()))
+    }
+
+    /// Parses every string with `PESEL::from_str` and returns aggregated statistics only
+    fn summarize_pesels(self) -> PeselBatchSummary {
+        self.parse_pesels()
+            .fold(PeselBatchSummary::default, |mut summary, result| {
+                summary.add_result(&result);
+                summary
+            })
+            .reduce(PeselBatchSummary::default, |mut summary, other| {
+                summary.merge(&other);
+                summary
+            })
+    }
+}
+
+impl<I> ParallelPeselIterator for I where I: ParallelIterator, I::Item: AsRef<str> {}
+
+/// Iterator validating PESEL numbers read line by line from any `BufRead` in chunks: every chunk is read sequentially,
+/// then validated in parallel. Only one chunk is kept in memory at a time.
+///
+/// Yields records of every chunk in input order. Blank lines are skipped (but still counted), lines which are not valid UTF-8
+/// are reported as `PeselError::BadFormat`, reading stops on the first I/O error - same as `PeselBatch`.
+pub struct PeselParallelBatch<R> {
+    reader:         R,
+    line_number:    usize,
+    chunk_size:     usize,
+    failed:         bool,
+}
+
+impl<R: BufRead> PeselParallelBatch<R> {
+    /// Panics if `chunk_size` is 0
+    pub fn new(reader: R, chunk_size: usize) -> PeselParallelBatch<R> {
+        assert!(chunk_size > 0, "chunk size has to be positive");
+        PeselParallelBatch {
+            reader,
+            line_number: 0,
+            chunk_size,
+            failed: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for PeselParallelBatch<R> {
+    type Item = std::io::Result<Vec<PeselBatchRecord>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        let mut line = Vec::new();
+        while chunk.len() < self.chunk_size {
+            match read_line(&mut self.reader, &mut line) {
+                Ok(true) => (),
+                Ok(false) => break,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                },
+            };
+            self.line_number += 1;
+            if !line.iter().all(u8::is_ascii_whitespace) {
+                chunk.push((self.line_number, line.clone()));
+            }
+        }
+        if chunk.is_empty() {
+            return None;
+        }
+
+        Some(Ok(chunk.into_par_iter()
+            .filter_map(|(line_number, line)| PeselBatchRecord::from_bytes(line_number, &line))
+            .collect()))
+    }
+}
+
+impl PESEL {
+    /// Parses all strings in parallel with `PESEL::from_str`, returning results in input order.
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    ///
+    /// let results = PESEL::par_parse(&["44051401458", "4405140145"]);
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].is_err());
+    /// ```
+    pub fn par_parse<S: AsRef<str> + Sync>(inputs: &[S]) -> Vec<Result<PESEL, PeselError>> {
+        inputs.par_iter().parse_pesels().collect()
+    }
+
+    /// Parses all strings in parallel and returns aggregated statistics only
+    pub fn par_summarize<S: AsRef<str> + Sync>(inputs: &[S]) -> PeselBatchSummary {
+        inputs.par_iter().summarize_pesels()
+    }
+
+    /// Validates PESEL numbers read from `reader` (one number per line) in chunks of `chunk_size` lines, every chunk in parallel.
+    /// Memory usage depends on the chunk size only.
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    ///
+    /// let input = "44051401458\n44051401459\nnot a PESEL\n";
+    /// for chunk in PESEL::par_from_reader(input.as_bytes(), 2) {
+    ///     for record in chunk.expect("unable to read input") {
+    ///         println!("line {}: {:?}", record.line_number, record.result);
+    ///     }
+    /// }
+    /// ```
+    pub fn par_from_reader<R: BufRead>(reader: R, chunk_size: usize) -> PeselParallelBatch<R> {
+        PeselParallelBatch::new(reader, chunk_size)
+    }
+
+    /// Validates all PESEL numbers read from `reader` (one number per line) in parallel, chunk by chunk (see `DEFAULT_CHUNK_SIZE`),
+    /// and returns aggregated statistics only. Same as `PESEL::summarize_reader`, but uses all CPU cores.
+    pub fn par_summarize_reader<R: BufRead>(reader: R) -> std::io::Result<PeselBatchSummary> {
+        let mut summary = PeselBatchSummary::default();
+        for chunk in PESEL::par_from_reader(reader, DEFAULT_CHUNK_SIZE) {
+            for record in chunk? {
+                summary.add(&record);
+            }
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod pesel_parallel_tests {
+    use super::ParallelPeselIterator;
+    use crate::pesel::PESEL;
+    use crate::pesel_parsing_error::{PeselError, PeselErrorKind};
+    use rayon::prelude::*;
+
+    const INPUT: &str = "44051401458\n44051401459\n4405140145a\n\n440514\n44053201458\n44951201458\n02270803624\n";
+
+    #[test]
+    fn results_should_be_returned_in_input_order() {
+        let inputs: Vec<String> = (0..10_000).map(|ordinal| format!("440514{:04}8", ordinal % 1000)).collect();
+        let results = PESEL::par_parse(&inputs);
+
+        assert_eq!(inputs.len(), results.len());
+        assert!(inputs.iter().zip(results.iter()).all(|(input, result)| result.as_ref().unwrap().as_str() == input));
+    }
+
+    #[test]
+    fn parallel_summary_should_match_sequential_one() {
+        let lines: Vec<&str> = INPUT.lines().filter(|line| !line.is_empty()).collect();
+        let sequential = PESEL::summarize_reader(INPUT.as_bytes()).unwrap();
+
+        assert_eq!(sequential, PESEL::par_summarize(&lines));
+        assert_eq!(sequential, lines.par_iter().summarize_pesels());
+        assert_eq!(sequential, PESEL::par_summarize_reader(INPUT.as_bytes()).unwrap());
+        assert_eq!(1, sequential.error_count(PeselErrorKind::DoBOutOfRange));
+    }
+
+    #[test]
+    fn chunks_should_keep_line_numbers() {
+        let chunks: Vec<_> = PESEL::par_from_reader(INPUT.as_bytes(), 3).map(|chunk| chunk.unwrap()).collect();
+        let sequential: Vec<_> = PESEL::from_reader(INPUT.as_bytes()).map(|record| record.unwrap()).collect();
+
+        assert_eq!(vec![3, 3, 1], chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>());
+        let parallel: Vec<_> = chunks.into_iter().flatten().collect();
+        assert_eq!(sequential.iter().map(|r| (r.line_number, &r.input, r.result.as_ref().err())).collect::<Vec<_>>(),
+            parallel.iter().map(|r| (r.line_number, &r.input, r.result.as_ref().err())).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn line_which_is_not_utf8_should_not_stop_reading() {
+        let input: &[u8] = b"44051401458\n4405\xB11401458\n44051401465\n";
+        let records: Vec<_> = PESEL::par_from_reader(input, 2).flat_map(|chunk| chunk.unwrap()).collect();
+
+        assert_eq!(vec![1, 2, 3], records.iter().map(|r| r.line_number).collect::<Vec<_>>());
+        assert_eq!(PeselError::BadFormat { index: 4, character: '\u{FFFD}' }, *records[1].result.as_ref().unwrap_err());
+        assert_eq!(PESEL::summarize_reader(input).unwrap(), PESEL::par_summarize_reader(input).unwrap());
+    }
+}
@@ -1,37 +1,194 @@
-use std::error::Error;
-
-#[derive(Debug, PartialEq)]
-pub enum PeselError {
+/// Kind of `PeselError`, without any context. Handy for matching, counting and comparing errors.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum PeselErrorKind {
     InvalidDoB,
     DoBOutOfRange,
     SizeError,
     BadFormat,
+    InvalidChecksum,
+    InvalidSerial,
+    GenderMismatch,
+    NotEnoughNumbers,
+    InvalidWeights,
+    InvalidRatio,
+}
+
+/// Error returned when PESEL can not be parsed or created. Variants carry details about what exactly was wrong;
+/// use `kind()` when only the type of error matters.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(tag = "kind"))]
+pub enum PeselError {
+    /// Date of birth does not exist (i.e. 30th of February). Contains decoded date.
+    #[cfg_attr(feature = "serde", serde(rename = "invalid_dob"))]
+    InvalidDoB { year: i32, month: u8, day: u8 },
+    /// Year of birth is out of PESEL range (1800-2299). Contains decoded year (just the two-digit year when century could not be decoded from month).
+    #[cfg_attr(feature = "serde", serde(rename = "dob_out_of_range"))]
+    DoBOutOfRange { year: i32 },
+    /// PESEL is not 11 characters (bytes) long
+    #[cfg_attr(feature = "serde", serde(rename = "size_error"))]
+    SizeError { expected: usize, found: usize },
+    /// PESEL contains a character which is not a digit. Contains byte index and the offending character.
+    #[cfg_attr(feature = "serde", serde(rename = "bad_format"))]
+    BadFormat { index: usize, character: char },
+    /// Check digit does not match the one calculated from other digits
+    #[cfg_attr(feature = "serde", serde(rename = "invalid_checksum"))]
+    InvalidChecksum { expected: u8, found: u8 },
+    #[cfg_attr(feature = "serde", serde(rename = "invalid_serial"))]
+    InvalidSerial,
+    #[cfg_attr(feature = "serde", serde(rename = "gender_mismatch"))]
+    GenderMismatch,
+    #[cfg_attr(feature = "serde", serde(rename = "not_enough_numbers"))]
+    NotEnoughNumbers,
+    #[cfg_attr(feature = "serde", serde(rename = "invalid_weights"))]
+    InvalidWeights,
+    /// Ratio (i.e. fraction of women) is not a finite number
+    #[cfg_attr(feature = "serde", serde(rename = "invalid_ratio"))]
+    InvalidRatio,
+}
+
+impl PeselErrorKind {
+    pub const fn pesel_error_to_message(&self) -> &'static str {
+        match *self {
+            PeselErrorKind::InvalidDoB => "Invalid birth date!",
+            PeselErrorKind::DoBOutOfRange => "Date is out of range!",
+            PeselErrorKind::SizeError => "PESEL has to be of 11 chars long!",
+            PeselErrorKind::BadFormat => "PESEL may only contain digits!",
+            PeselErrorKind::InvalidChecksum => "Invalid checksum!",
+            PeselErrorKind::InvalidSerial => "Serial number is out of range!",
+            PeselErrorKind::GenderMismatch => "Gender digit does not match gender!",
+            PeselErrorKind::NotEnoughNumbers => "Not enough PESEL numbers available in requested range!",
+            PeselErrorKind::InvalidWeights => "Weights of population distribution are invalid!",
+            PeselErrorKind::InvalidRatio => "Ratio has to be a finite number!",
+        }
+    }
+
+    /// Returns stable, machine-readable name of the error kind (used for serialization and by the command line tool)
+    pub fn pesel_error_to_tag(&self) -> &'static str {
+        match *self {
+            PeselErrorKind::InvalidDoB => "invalid_dob",
+            PeselErrorKind::DoBOutOfRange => "dob_out_of_range",
+            PeselErrorKind::SizeError => "size_error",
+            PeselErrorKind::BadFormat => "bad_format",
+            PeselErrorKind::InvalidChecksum => "invalid_checksum",
+            PeselErrorKind::InvalidSerial => "invalid_serial",
+            PeselErrorKind::GenderMismatch => "gender_mismatch",
+            PeselErrorKind::NotEnoughNumbers => "not_enough_numbers",
+            PeselErrorKind::InvalidWeights => "invalid_weights",
+            PeselErrorKind::InvalidRatio => "invalid_ratio",
+        }
+    }
+
+    /// Inverse of `pesel_error_to_tag`
+    pub fn pesel_error_from_tag(tag: &str) -> Option<PeselErrorKind> {
+        match tag {
+            "invalid_dob" => Some(PeselErrorKind::InvalidDoB),
+            "dob_out_of_range" => Some(PeselErrorKind::DoBOutOfRange),
+            "size_error" => Some(PeselErrorKind::SizeError),
+            "bad_format" => Some(PeselErrorKind::BadFormat),
+            "invalid_checksum" => Some(PeselErrorKind::InvalidChecksum),
+            "invalid_serial" => Some(PeselErrorKind::InvalidSerial),
+            "gender_mismatch" => Some(PeselErrorKind::GenderMismatch),
+            "not_enough_numbers" => Some(PeselErrorKind::NotEnoughNumbers),
+            "invalid_weights" => Some(PeselErrorKind::InvalidWeights),
+            "invalid_ratio" => Some(PeselErrorKind::InvalidRatio),
+            _ => None,
+        }
+    }
 }
 
 impl PeselError {
-    pub fn new (kind: PeselError) -> PeselError {
+    /// Kept for compatibility with 0.1 - returns the error it has been given
+    #[deprecated(since = "0.2.0", note = "construct `PeselError` variants directly, use `kind()` to compare errors")]
+    pub fn new(kind: PeselError) -> PeselError {
         kind
     }
 
-    pub fn pesel_error_to_message(&self) -> &str {
+    /// Returns kind of the error (without context)
+    pub const fn kind(&self) -> PeselErrorKind {
         match *self {
-            PeselError::InvalidDoB => "Invalid birth date!",
-            PeselError::DoBOutOfRange => "Date is out of range!",
-            PeselError::SizeError => "PESEL has to be of 11 chars long!",
-            PeselError::BadFormat => "PESEL may only contain digits!",
+            PeselError::InvalidDoB { .. } => PeselErrorKind::InvalidDoB,
+            PeselError::DoBOutOfRange { .. } => PeselErrorKind::DoBOutOfRange,
+            PeselError::SizeError { .. } => PeselErrorKind::SizeError,
+            PeselError::BadFormat { .. } => PeselErrorKind::BadFormat,
+            PeselError::InvalidChecksum { .. } => PeselErrorKind::InvalidChecksum,
+            PeselError::InvalidSerial => PeselErrorKind::InvalidSerial,
+            PeselError::GenderMismatch => PeselErrorKind::GenderMismatch,
+            PeselError::NotEnoughNumbers => PeselErrorKind::NotEnoughNumbers,
+            PeselError::InvalidWeights => PeselErrorKind::InvalidWeights,
+            PeselError::InvalidRatio => PeselErrorKind::InvalidRatio,
         }
     }
+
+    /// Returns generic message describing kind of the error (without context)
+    pub const fn pesel_error_to_message(&self) -> &'static str {
+        self.kind().pesel_error_to_message()
+    }
+
+    /// Returns stable, machine-readable name of the error kind (see `PeselErrorKind::pesel_error_to_tag`)
+    pub fn pesel_error_to_tag(&self) -> &'static str {
+        self.kind().pesel_error_to_tag()
+    }
 }
 
-impl std::fmt::Display for PeselError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for PeselErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{}", self.pesel_error_to_message())
     }
 }
 
-impl Error for PeselError {
+impl core::fmt::Display for PeselError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let message = self.pesel_error_to_message();
+        match *self {
+            PeselError::InvalidDoB { year, month, day } => write!(f, "{} ({}-{:02}-{:02})", message, year, month, day),
+            PeselError::DoBOutOfRange { year } => write!(f, "{} (year {})", message, year),
+            PeselError::SizeError { found, .. } => write!(f, "{} (found {})", message, found),
+            PeselError::BadFormat { index, character } => write!(f, "{} (found {:?} at position {})", message, character, index),
+            PeselError::InvalidChecksum { expected, found } => write!(f, "{} (expected {}, found {})", message, expected, found),
+            _ => write!(f, "{}", message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PeselError {
     fn description(&self) -> &str {
-        &self.pesel_error_to_message()
+        self.pesel_error_to_message()
     }
 }
 
+#[cfg(test)]
+mod pesel_error_tests {
+    use super::{PeselError, PeselErrorKind};
+
+    #[test]
+    fn error_message_should_contain_context() {
+        assert_eq!("PESEL may only contain digits! (found 'a' at position 10)", PeselError::BadFormat { index: 10, character: 'a' }.to_string());
+        assert_eq!("Invalid birth date! (1993-02-29)", PeselError::InvalidDoB { year: 1993, month: 2, day: 29 }.to_string());
+        assert_eq!("PESEL has to be of 11 chars long! (found 12)", PeselError::SizeError { expected: 11, found: 12 }.to_string());
+        assert_eq!("Invalid checksum! (expected 8, found 9)", PeselError::InvalidChecksum { expected: 8, found: 9 }.to_string());
+        assert_eq!("Gender digit does not match gender!", PeselError::GenderMismatch.to_string());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_constructor_should_return_given_error() {
+        assert_eq!(PeselError::InvalidSerial, PeselError::new(PeselError::InvalidSerial));
+    }
+
+    #[test]
+    fn kind_should_ignore_context() {
+        assert_eq!(PeselErrorKind::DoBOutOfRange, PeselError::DoBOutOfRange { year: 1799 }.kind());
+        assert_eq!(PeselError::DoBOutOfRange { year: 1799 }.kind(), PeselError::DoBOutOfRange { year: 2300 }.kind());
+    }
+
+    #[test]
+    fn every_tag_should_map_back_to_its_kind() {
+        let kinds = [PeselErrorKind::InvalidDoB, PeselErrorKind::DoBOutOfRange, PeselErrorKind::SizeError, PeselErrorKind::BadFormat,
+            PeselErrorKind::InvalidChecksum, PeselErrorKind::InvalidSerial, PeselErrorKind::GenderMismatch,
+            PeselErrorKind::NotEnoughNumbers, PeselErrorKind::InvalidWeights, PeselErrorKind::InvalidRatio];
+        for kind in kinds.iter() {
+            assert_eq!(Some(*kind), PeselErrorKind::pesel_error_from_tag(kind.pesel_error_to_tag()));
+        }
+    }
+}
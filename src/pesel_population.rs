@@ -0,0 +1,212 @@
+use crate::pesel::{PESEL, PeselGender};
+use crate::pesel_parsing_error::PeselError;
+use chrono::{Datelike, Duration, NaiveDate};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Generator of synthetic PESEL datasets following configurable age structure (population pyramid) and sex ratio.
+///
+/// Weights may be assigned to individual birth years (`year_weight`) or to age buckets (`age_weight`), relative to reference year.
+/// Weights are relative - they do not have to sum up to any particular value. Within a birth year, birth dates are uniform.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_population::PeselPopulation;
+///
+/// let population = PeselPopulation::new(2019)
+///     .age_weight(0..=17, 18.0)
+///     .age_weight(18..=64, 63.0)
+///     .age_weight(65..=99, 19.0)
+///     .female_ratio(0.52)
+///     .seed(2019);
+/// let pesels: Vec<_> = population.generate().unwrap().take(1000).collect();
+/// assert_eq!(1000, pesels.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PeselPopulation {
+    reference_year: i32,                // year ages are counted against
+    year_weights:   BTreeMap<i32, f64>, // birth year -> weight
+    female_ratio:   f64,                // probability of generating PESEL of a woman
+    seed:           Option<u64>,        // seed for reproducible output, random if not set
+}
+
+impl PeselPopulation {
+    /// Creates empty distribution (at least one weight has to be added before generating). `reference_year` is used to convert ages to birth years.
+    pub fn new(reference_year: i32) -> PeselPopulation {
+        PeselPopulation {
+            reference_year,
+            year_weights: BTreeMap::new(),
+            female_ratio: 0.5,
+            seed: None,
+        }
+    }
+
+    /// Adds weight of given birth year (weights of the same year are summed up)
+    pub fn year_weight(mut self, year: i32, weight: f64) -> PeselPopulation {
+        *self.year_weights.entry(year).or_insert(0.0) += weight;
+        self
+    }
+
+    /// Adds weight of given age bucket (i.e. `18..=64`). Weight is split evenly between birth years `reference_year - age` of all ages in the bucket.
+    pub fn age_weight(mut self, ages: RangeInclusive<u16>, weight: f64) -> PeselPopulation {
+        let years = ages.clone().count();
+        for age in ages {
+            *self.year_weights.entry(self.reference_year - age as i32).or_insert(0.0) += weight / years as f64;
+        }
+        self
+    }
+
+    /// Sets fraction (0.0 - 1.0) of generated PESELs belonging to women (0.5 by default). Values out of range are clamped,
+    /// NaN or infinity makes `generate` return `PeselError::InvalidRatio`.
+    pub fn female_ratio(mut self, ratio: f64) -> PeselPopulation {
+        self.female_ratio = ratio;
+        self
+    }
+
+    /// Makes output reproducible: population with the same settings and seed always yields the same sequence
+    /// (for a given version of `rand` crate)
+    pub fn seed(mut self, seed: u64) -> PeselPopulation {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns infinite iterator of random PESEL numbers following the distribution (use `take` to limit the number of items).
+    /// Note: numbers are not guaranteed to be unique (see `PeselGenerator` for that).
+    ///
+    /// Errors:
+    /// - `PeselError::DoBOutOfRange` - when weight has been assigned to a birth year out of PESEL range (1800-2299)
+    /// - `PeselError::InvalidWeights` - when no weights have been added, all of them are zero, any of them is negative or not a finite number,
+    ///   or their sum is too large to be represented
+    /// - `PeselError::InvalidRatio` - when female ratio is not a finite number
+    pub fn generate(&self) -> Result<PopulationPesels, PeselError> {
+        if let Some(year) = self.year_weights.keys().copied().find(|year| ! PESEL::is_date_in_range(*year)) {
+            return Err(PeselError::DoBOutOfRange { year });
+        }
+        let years = self.year_weights.keys()
+            .map(|year| PeselPopulation::days_of_year(*year).ok_or(PeselError::DoBOutOfRange { year: *year }))
+            .collect::<Result<Vec<_>, PeselError>>()?;
+        // `WeightedIndex` panics on NaN and infinite weights instead of returning an error
+        if !self.year_weights.values().sum::<f64>().is_finite() {
+            return Err(PeselError::InvalidWeights);
+        }
+        let weights = WeightedIndex::new(self.year_weights.values())
+            .map_err(|_| PeselError::InvalidWeights)?;
+        if !self.female_ratio.is_finite() {
+            return Err(PeselError::InvalidRatio);
+        }
+
+        Ok(PopulationPesels {
+            rng: match self.seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            },
+            years,
+            weights,
+            female_ratio: self.female_ratio.clamp(0.0, 1.0),
+        })
+    }
+
+    /// Utility function - returns the first day of given year and number of days in it
+    fn days_of_year(year: i32) -> Option<(NaiveDate, i64)> {
+        let first_day = NaiveDate::from_ymd_opt(year, 1, 1)?;
+        let next_year = NaiveDate::from_ymd_opt(year + 1, 1, 1)?;
+        Some((first_day, (next_year - first_day).num_days()))
+    }
+}
+
+/// Infinite iterator of random PESEL numbers following population distribution (see `PeselPopulation::generate`)
+#[derive(Debug)]
+pub struct PopulationPesels {
+    rng:            StdRng,
+    years:          Vec<(NaiveDate, i64)>,     // first day and number of days of every weighted birth year
+    weights:        WeightedIndex<f64>,
+    female_ratio:   f64,
+}
+
+impl Iterator for PopulationPesels {
+    type Item = PESEL;
+
+    fn next(&mut self) -> Option<PESEL> {
+        let (first_day, days_in_year) = self.years[self.weights.sample(&mut self.rng)];
+        let date = first_day + Duration::days(self.rng.gen_range(0, days_in_year));
+
+        let gender = match self.rng.gen_bool(self.female_ratio) {
+            true => PeselGender::Female,
+            false => PeselGender::Male,
+        };
+
+        let pesel = PESEL::new_with_rng(date.year() as u16, date.month() as u8, date.day() as u8, gender, &mut self.rng)
+            .expect("birth years have been validated when iterator was created");
+        Some(pesel)
+    }
+}
+
+#[cfg(test)]
+mod pesel_population_tests {
+    use super::PeselPopulation;
+    use crate::pesel::PeselGender;
+    use crate::pesel_parsing_error::PeselError;
+
+    #[test]
+    fn birth_years_should_follow_weights() {
+        let pesels: Vec<_> = PeselPopulation::new(2019).year_weight(1980, 3.0).year_weight(1990, 1.0).seed(7)
+            .generate().unwrap().take(4000).collect();
+        let born_in_1980 = pesels.iter().filter(|p| p.pesel_number().starts_with("80")).count();
+        let born_in_1990 = pesels.iter().filter(|p| p.pesel_number().starts_with("90")).count();
+
+        assert_eq!(4000, born_in_1980 + born_in_1990);
+        assert!((2800..=3200).contains(&born_in_1980), "born in 1980: {}", born_in_1980);
+        assert!(pesels.iter().all(|p| p.is_valid()));
+    }
+
+    #[test]
+    fn age_buckets_should_be_converted_to_birth_years() {
+        let pesels: Vec<_> = PeselPopulation::new(2019).age_weight(18..=19, 1.0).seed(1)
+            .generate().unwrap().take(100).collect();
+
+        assert!(pesels.iter().all(|p| p.pesel_number().starts_with("00") || p.pesel_number().starts_with("01")));
+    }
+
+    #[test]
+    fn sex_ratio_should_be_respected() {
+        let pesels: Vec<_> = PeselPopulation::new(2019).year_weight(1950, 1.0).female_ratio(0.25).seed(3)
+            .generate().unwrap().take(4000).collect();
+        let females = pesels.iter().filter(|p| p.gender() == PeselGender::Female).count();
+
+        assert!((900..=1100).contains(&females), "females: {}", females);
+    }
+
+    #[test]
+    fn the_same_seed_should_give_the_same_pesels() {
+        let population = PeselPopulation::new(2019).age_weight(0..=100, 1.0).seed(42);
+        let first: Vec<String> = population.generate().unwrap().take(100).map(|p| p.pesel_number()).collect();
+        let second: Vec<String> = population.generate().unwrap().take(100).map(|p| p.pesel_number()).collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn invalid_weights_should_result_in_error() {
+        assert_eq!(PeselError::InvalidWeights, PeselPopulation::new(2019).generate().unwrap_err());
+        assert_eq!(PeselError::InvalidWeights, PeselPopulation::new(2019).year_weight(1980, 0.0).generate().unwrap_err());
+        assert_eq!(PeselError::InvalidWeights, PeselPopulation::new(2019).year_weight(1980, -1.0).generate().unwrap_err());
+        assert_eq!(PeselError::DoBOutOfRange { year: 1799 }, PeselPopulation::new(2019).year_weight(1799, 1.0).generate().unwrap_err());
+    }
+
+    #[test]
+    fn non_finite_weights_should_result_in_error() {
+        assert_eq!(PeselError::InvalidWeights, PeselPopulation::new(2019).year_weight(1980, f64::NAN).generate().unwrap_err());
+        assert_eq!(PeselError::InvalidWeights, PeselPopulation::new(2019).year_weight(1980, f64::INFINITY).generate().unwrap_err());
+        assert_eq!(PeselError::InvalidWeights, PeselPopulation::new(2019).age_weight(18..=64, f64::INFINITY).generate().unwrap_err());
+        assert_eq!(PeselError::InvalidWeights, PeselPopulation::new(2019).year_weight(1980, f64::MAX).year_weight(1990, f64::MAX).generate().unwrap_err());
+    }
+
+    #[test]
+    fn non_finite_sex_ratio_should_result_in_error() {
+        assert_eq!(PeselError::InvalidRatio, PeselPopulation::new(2019).year_weight(1980, 1.0).female_ratio(f64::NAN).generate().unwrap_err());
+        assert_eq!(PeselError::InvalidRatio, PeselPopulation::new(2019).year_weight(1980, 1.0).female_ratio(f64::NEG_INFINITY).generate().unwrap_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! Serde support (enabled with `serde` cargo feature).
+//!
+//! - `PESEL` is serialized as its 11 digit string. Deserialization performs exactly the same checks as `PESEL::from_str`, which means
+//!   numbers failing the checksum check are accepted by default. Use `#[serde(with = "pesel::pesel_serde::strict")]` on a field to reject them.
+//! - `CompactPesel` is serialized the same way as `PESEL`
+//! - `PeselGender` is serialized as `"male"` / `"female"`
+//! - `PeselErrorKind` is serialized as its tag (see `PeselErrorKind::pesel_error_to_tag`), i.e. `"invalid_dob"`, `"size_error"` etc.
+//! - `PeselError` is serialized as an object with the tag in `kind` field, followed by error context,
+//!   i.e. `{"kind":"bad_format","index":10,"character":"a"}`
+//! - `PeselExplanation` (see `PESEL::explain`) can be serialized only
+//!
+//! Example:
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use pesel::pesel::PESEL as PESEL;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Customer {
+//!     name: String,
+//!     pesel: PESEL,                                       // accepts PESELs failing checksum check
+//!     #[serde(with = "pesel::pesel_serde::strict")]
+//!     guardian_pesel: PESEL,                              // rejects PESELs failing checksum check
+//! }
+//! # }
+//! ```
+use crate::pesel::{PESEL, PeselGender};
+use crate::pesel_compact::CompactPesel;
+use crate::pesel_parsing_error::PeselErrorKind;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::str::FromStr;
+
+impl Serialize for PESEL {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.pesel_number())
+    }
+}
+
+impl<'de> Deserialize<'de> for PESEL {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        lenient::deserialize(deserializer)
+    }
+}
+
+impl Serialize for CompactPesel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CompactPesel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(CompactPeselVisitor)
+    }
+}
+
+impl Serialize for PeselGender {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match *self {
+            PeselGender::Male => "male",
+            PeselGender::Female => "female",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PeselGender {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(TagVisitor::new("\"male\" or \"female\"", |tag| match tag {
+            "male" => Some(PeselGender::Male),
+            "female" => Some(PeselGender::Female),
+            _ => None,
+        }))
+    }
+}
+
+impl Serialize for PeselErrorKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.pesel_error_to_tag())
+    }
+}
+
+impl<'de> Deserialize<'de> for PeselErrorKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(TagVisitor::new("PESEL error tag", PeselErrorKind::pesel_error_from_tag))
+    }
+}
+
+/// Accepts every PESEL `PESEL::from_str` accepts, including numbers failing checksum check (this is the default behavior).
+/// Meant to be used with `#[serde(with = "pesel::pesel_serde::lenient")]`.
+pub mod lenient {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(pesel: &PESEL, serializer: S) -> Result<S::Ok, S::Error> {
+        pesel.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PESEL, D::Error> {
+        deserializer.deserialize_str(PeselVisitor { strict: false })
+    }
+}
+
+/// Same as `lenient`, but rejects PESEL numbers for which `is_valid()` is false.
+/// Meant to be used with `#[serde(with = "pesel::pesel_serde::strict")]`.
+pub mod strict {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(pesel: &PESEL, serializer: S) -> Result<S::Ok, S::Error> {
+        pesel.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PESEL, D::Error> {
+        deserializer.deserialize_str(PeselVisitor { strict: true })
+    }
+}
+
+struct PeselVisitor {
+    strict: bool,
+}
+
+impl<'de> Visitor<'de> for PeselVisitor {
+    type Value = PESEL;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "11 digit PESEL number")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<PESEL, E> {
+        match self.strict {
+            true => PESEL::from_str_strict(value).map_err(E::custom),
+            false => PESEL::from_str(value).map_err(E::custom),
+        }
+    }
+}
+
+struct CompactPeselVisitor;
+
+impl<'de> Visitor<'de> for CompactPeselVisitor {
+    type Value = CompactPesel;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "11 digit PESEL number")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<CompactPesel, E> {
+        CompactPesel::from_str(value).map_err(E::custom)
+    }
+}
+
+/// Visitor for enums serialized as plain string tags
+struct TagVisitor<T> {
+    expecting:  &'static str,
+    from_tag:   fn(&str) -> Option<T>,
+}
+
+impl<T> TagVisitor<T> {
+    fn new(expecting: &'static str, from_tag: fn(&str) -> Option<T>) -> TagVisitor<T> {
+        TagVisitor { expecting, from_tag }
+    }
+}
+
+impl<'de, T> Visitor<'de> for TagVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        (self.from_tag)(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+#[cfg(test)]
+mod pesel_serde_tests {
+    use crate::pesel::{PESEL, PeselGender};
+    use crate::pesel_compact::CompactPesel;
+    use crate::pesel_parsing_error::{PeselError, PeselErrorKind};
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct StrictRecord {
+        #[serde(with = "crate::pesel_serde::strict")]
+        pesel: PESEL,
+    }
+
+    #[test]
+    fn pesel_should_be_serialized_as_string() {
+        let pesel: PESEL = "44051401458".parse().unwrap();
+
+        assert_eq!("\"44051401458\"", serde_json::to_string(&pesel).unwrap());
+    }
+
+    #[test]
+    fn pesel_deserialization_should_perform_same_checks_as_from_str() {
+        let pesel: PESEL = serde_json::from_str("\"44051401459\"").unwrap();
+        assert!(!pesel.is_valid());
+
+        assert!(serde_json::from_str::<PESEL>("\"4405140145a\"").is_err());
+        assert!(serde_json::from_str::<PESEL>("\"44053201458\"").is_err());
+        assert!(serde_json::from_str::<PESEL>("44051401458").is_err());
+    }
+
+    #[test]
+    fn strict_deserialization_should_reject_invalid_checksum() {
+        assert!(serde_json::from_str::<StrictRecord>("{\"pesel\":\"44051401459\"}").is_err());
+
+        let record: StrictRecord = serde_json::from_str("{\"pesel\":\"44051401458\"}").unwrap();
+        assert_eq!("44051401458", record.pesel.pesel_number());
+    }
+
+    #[test]
+    fn compact_pesel_should_round_trip_as_string() {
+        let pesel: CompactPesel = serde_json::from_str("\"44051401458\"").unwrap();
+
+        assert_eq!("\"44051401458\"", serde_json::to_string(&pesel).unwrap());
+        assert!(serde_json::from_str::<CompactPesel>("\"44053201458\"").is_err());
+    }
+
+    #[test]
+    fn gender_should_round_trip_as_tag() {
+        assert_eq!("\"female\"", serde_json::to_string(&PeselGender::Female).unwrap());
+        assert_eq!(PeselGender::Male, serde_json::from_str::<PeselGender>("\"male\"").unwrap());
+        assert!(serde_json::from_str::<PeselGender>("\"Male\"").is_err());
+    }
+
+    #[test]
+    fn error_kind_should_round_trip_as_tag() {
+        for kind in &[PeselErrorKind::InvalidDoB, PeselErrorKind::DoBOutOfRange, PeselErrorKind::SizeError, PeselErrorKind::BadFormat,
+            PeselErrorKind::InvalidChecksum, PeselErrorKind::InvalidSerial, PeselErrorKind::GenderMismatch,
+            PeselErrorKind::NotEnoughNumbers, PeselErrorKind::InvalidWeights, PeselErrorKind::InvalidRatio] {
+            let json = serde_json::to_string(kind).unwrap();
+            assert_eq!(format!("\"{}\"", kind.pesel_error_to_tag()), json);
+            assert_eq!(*kind, serde_json::from_str::<PeselErrorKind>(&json).unwrap());
+        }
+    }
+
+    #[test]
+    fn error_should_round_trip_with_context() {
+        let error = PeselError::BadFormat { index: 10, character: 'a' };
+        let json = serde_json::to_string(&error).unwrap();
+
+        assert_eq!("{\"kind\":\"bad_format\",\"index\":10,\"character\":\"a\"}", json);
+        assert_eq!(error, serde_json::from_str::<PeselError>(&json).unwrap());
+        assert_eq!("{\"kind\":\"gender_mismatch\"}", serde_json::to_string(&PeselError::GenderMismatch).unwrap());
+        assert_eq!(PeselError::InvalidWeights, serde_json::from_str::<PeselError>("{\"kind\":\"invalid_weights\"}").unwrap());
+    }
+}
@@ -0,0 +1,175 @@
+//! Validation of PESELs coming from async streams and readers (requires `futures` feature).
+//!
+//! Everything is lazy: a PESEL is parsed only when the consumer polls for the next result, so backpressure is preserved
+//! and no blocking threads are needed. Works with any executor - for tokio readers use `tokio_util::compat` to get `futures::io::AsyncBufRead`.
+use crate::pesel::PESEL;
+use crate::pesel_batch::{PeselBatchRecord, PeselBatchSummary};
+use crate::pesel_parsing_error::PeselError;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures::io::AsyncBufRead;
+use futures::stream::{Map, Stream, StreamExt};
+use std::str::FromStr;
+
+/// Stream returned by `PeselStreamExt::parse_pesels`
+pub type ParsePesels<S> = Map<S, fn(<S as Stream>::Item) -> Result<PESEL, PeselError>>;
+
+/// Validation of any stream of strings.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_stream::PeselStreamExt;
+/// use futures::stream::{self, StreamExt};
+///
+/// let results: Vec<_> = futures::executor::block_on(stream::iter(vec!["44051401458", "4405140145a"]).parse_pesels().collect());
+/// assert!(results[0].as_ref().unwrap().is_valid());
+/// assert!(results[1].is_err());
+/// ```
+pub trait PeselStreamExt: Stream + Sized where Self::Item: AsRef<str> {
+    /// Parses every string with `PESEL::from_str`, one at a time as the results are polled
+    fn parse_pesels(self) -> ParsePesels<Self> {
+        self.map(|s| PESEL::from_str(s.as_ref()))
+    }
+}
+
+impl<S> PeselStreamExt for S where S: Stream, S::Item: AsRef<str> {}
+
+/// Stream validating PESEL numbers read line by line (one number per line) from any `AsyncBufRead`.
+///
+/// Blank lines are skipped (but still counted), lines which are not valid UTF-8 are reported as `PeselError::BadFormat`,
+/// reading stops on the first I/O error - same as `PeselBatch`.
+pub struct PeselAsyncBatch<R> {
+    reader:         R,
+    buffer:         Vec<u8>,    // current line, possibly read partially
+    line_number:    usize,
+    failed:         bool,
+}
+
+impl<R: AsyncBufRead + Unpin> PeselAsyncBatch<R> {
+    pub fn new(reader: R) -> PeselAsyncBatch<R> {
+        PeselAsyncBatch {
+            reader,
+            buffer: Vec::new(),
+            line_number: 0,
+            failed: false,
+        }
+    }
+
+    /// Utility function - appends bytes up to the end of line to `buffer`, returns `false` at the end of input
+    fn poll_read_line(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<bool>> {
+        loop {
+            let available = futures::ready!(Pin::new(&mut self.reader).poll_fill_buf(cx))?;
+            if available.is_empty() {
+                return Poll::Ready(Ok(!self.buffer.is_empty()));
+            }
+            let (length, end_of_line) = match available.iter().position(|byte| *byte == b'\n') {
+                Some(index) => (index + 1, true),
+                None => (available.len(), false),
+            };
+            self.buffer.extend_from_slice(&available[..length]);
+            Pin::new(&mut self.reader).consume(length);
+            if end_of_line {
+                return Poll::Ready(Ok(true));
+            }
+        }
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for PeselAsyncBatch<R> {
+    type Item = std::io::Result<PeselBatchRecord>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.failed {
+            return Poll::Ready(None);
+        }
+        loop {
+            match futures::ready!(self.poll_read_line(cx)) {
+                Ok(true) => (),
+                Ok(false) => return Poll::Ready(None),
+                Err(e) => {
+                    self.failed = true;
+                    return Poll::Ready(Some(Err(e)));
+                },
+            };
+            self.line_number += 1;
+
+            let record = PeselBatchRecord::from_bytes(self.line_number, &self.buffer);
+            self.buffer.clear();
+            if let Some(record) = record {
+                return Poll::Ready(Some(Ok(record)));
+            }
+        }
+    }
+}
+
+impl PESEL {
+    /// Validates PESEL numbers read from async `reader` (one number per line), lazily yielding a `PeselBatchRecord` for every non-blank line.
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    /// use futures::stream::StreamExt;
+    ///
+    /// futures::executor::block_on(async {
+    ///     let mut records = PESEL::from_async_reader("44051401458\n4405140145a\n".as_bytes());
+    ///     while let Some(record) = records.next().await {
+    ///         let record = record.expect("unable to read input");
+    ///         println!("line {}: {:?}", record.line_number, record.result);
+    ///     }
+    /// });
+    /// ```
+    pub fn from_async_reader<R: AsyncBufRead + Unpin>(reader: R) -> PeselAsyncBatch<R> {
+        PeselAsyncBatch::new(reader)
+    }
+
+    /// Validates all PESEL numbers read from async `reader` (one number per line) and returns aggregated statistics only
+    pub async fn summarize_async_reader<R: AsyncBufRead + Unpin>(reader: R) -> std::io::Result<PeselBatchSummary> {
+        let mut summary = PeselBatchSummary::default();
+        let mut records = PESEL::from_async_reader(reader);
+        while let Some(record) = records.next().await {
+            summary.add(&record?);
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod pesel_stream_tests {
+    use super::PeselStreamExt;
+    use crate::pesel::PESEL;
+    use crate::pesel_parsing_error::PeselError;
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+
+    const INPUT: &str = "44051401458\n44051401459\n4405140145a\n\n440514\n44053201458\n44951201458\n02270803624\n";
+
+    #[test]
+    fn stream_of_strings_should_be_parsed_in_order() {
+        let inputs = vec!["44051401458".to_string(), "4405140145a".to_string(), "02270803624".to_string()];
+        let results: Vec<_> = block_on(stream::iter(inputs).parse_pesels().collect());
+
+        assert_eq!("44051401458", results[0].as_ref().unwrap().as_str());
+        assert_eq!(PeselError::BadFormat { index: 10, character: 'a' }, *results[1].as_ref().unwrap_err());
+        assert_eq!("02270803624", results[2].as_ref().unwrap().as_str());
+    }
+
+    #[test]
+    fn async_reader_should_behave_like_blocking_one() {
+        let records: Vec<_> = block_on(PESEL::from_async_reader(INPUT.as_bytes()).map(|record| record.unwrap()).collect());
+        let expected: Vec<_> = PESEL::from_reader(INPUT.as_bytes()).map(|record| record.unwrap()).collect();
+
+        assert_eq!(expected.iter().map(|r| (r.line_number, &r.input, r.result.as_ref().err())).collect::<Vec<_>>(),
+            records.iter().map(|r| (r.line_number, &r.input, r.result.as_ref().err())).collect::<Vec<_>>());
+        assert_eq!(PESEL::summarize_reader(INPUT.as_bytes()).unwrap(), block_on(PESEL::summarize_async_reader(INPUT.as_bytes())).unwrap());
+    }
+
+    #[test]
+    fn line_which_is_not_utf8_should_not_stop_reading() {
+        let input: &[u8] = b"44051401458\n4405\xB11401458\n44051401465";
+        let records: Vec<_> = block_on(PESEL::from_async_reader(input).map(|record| record.unwrap()).collect());
+
+        assert_eq!(vec![1, 2, 3], records.iter().map(|r| r.line_number).collect::<Vec<_>>());
+        assert_eq!(PeselError::BadFormat { index: 4, character: '\u{FFFD}' }, *records[1].result.as_ref().unwrap_err());
+        assert!(records[2].is_valid());
+    }
+}
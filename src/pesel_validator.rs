@@ -0,0 +1,124 @@
+use crate::pesel::PESEL;
+use crate::pesel_exceptions::{PeselExceptionRegistry, PeselValidity};
+use crate::pesel_parsing_error::PeselError;
+use core::str::FromStr;
+
+/// What to do with PESEL numbers failing the checksum check
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ChecksumPolicy {
+    /// Accept the number, only recording the failure in `PESEL::is_valid` (behavior of `PESEL::from_str`)
+    Lenient,
+    /// Reject the number with `PeselError::InvalidChecksum`, unless it is a known exception (see `PeselValidator::exceptions`)
+    Strict,
+}
+
+/// Configurable PESEL validator, for callers who want to choose validation policy explicitly.
+///
+/// Example:
+/// ```rust
+/// use pesel::pesel_validator::{PeselValidator, ChecksumPolicy};
+/// use pesel::pesel_parsing_error::PeselError;
+///
+/// let validator = PeselValidator::new().checksum_policy(ChecksumPolicy::Strict);
+/// assert!(validator.validate("44051401458").is_ok());
+/// assert_eq!(PeselError::InvalidChecksum { expected: 8, found: 9 }, validator.validate("44051401459").unwrap_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PeselValidator {
+    checksum_policy:    ChecksumPolicy,             // how to treat numbers failing checksum check
+    exceptions:         PeselExceptionRegistry,     // officially issued numbers failing checksum check
+}
+
+impl PeselValidator {
+    /// Creates validator rejecting numbers failing the checksum check (`ChecksumPolicy::Strict`)
+    pub fn new() -> PeselValidator {
+        PeselValidator {
+            checksum_policy: ChecksumPolicy::Strict,
+            exceptions: PeselExceptionRegistry::new(),
+        }
+    }
+
+    /// Sets policy for numbers failing the checksum check
+    pub fn checksum_policy(mut self, policy: ChecksumPolicy) -> PeselValidator {
+        self.checksum_policy = policy;
+        self
+    }
+
+    /// Sets registry of officially issued numbers failing the checksum check. These are accepted even with `ChecksumPolicy::Strict`.
+    pub fn exceptions(mut self, registry: PeselExceptionRegistry) -> PeselValidator {
+        self.exceptions = registry;
+        self
+    }
+
+    /// Parses and validates PESEL according to validator's policy
+    pub fn validate(&self, s: &str) -> Result<PESEL, PeselError> {
+        let pesel = PESEL::from_str(s)?;
+        match (self.checksum_policy, self.validity(&pesel)) {
+            (ChecksumPolicy::Strict, PeselValidity::InvalidChecksum) => Err(pesel.checksum_error().expect("checksum has been checked")),
+            _ => Ok(pesel),
+        }
+    }
+
+    /// Returns validity status of PESEL, taking validator's exception registry into account
+    pub fn validity(&self, pesel: &PESEL) -> PeselValidity {
+        pesel.validity(&self.exceptions)
+    }
+}
+
+impl Default for PeselValidator {
+    fn default() -> PeselValidator {
+        PeselValidator::new()
+    }
+}
+
+impl PESEL {
+    /// Same as `PESEL::from_str`, but numbers failing the checksum check are rejected with `PeselError::InvalidChecksum`
+    ///
+    /// Example:
+    /// ```rust
+    /// use pesel::pesel::PESEL as PESEL;
+    ///
+    /// assert!(PESEL::from_str_strict("44051401458").is_ok());
+    /// assert!(PESEL::from_str_strict("44051401459").is_err());
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<PESEL, PeselError> {
+        PeselValidator::new().validate(s)
+    }
+}
+
+#[cfg(test)]
+mod pesel_validator_tests {
+    use super::{ChecksumPolicy, PeselValidator};
+    use crate::pesel::PESEL;
+    use crate::pesel_exceptions::{PeselExceptionRegistry, PeselValidity};
+    use crate::pesel_parsing_error::PeselError;
+
+    #[test]
+    fn strict_parsing_should_reject_invalid_checksum() {
+        assert_eq!("44051401458", PESEL::from_str_strict("44051401458").unwrap().pesel_number());
+        assert_eq!(PeselError::InvalidChecksum { expected: 8, found: 9 }, PESEL::from_str_strict("44051401459").unwrap_err());
+    }
+
+    #[test]
+    fn strict_parsing_should_report_other_errors_first() {
+        assert_eq!(PeselError::BadFormat { index: 10, character: 'a' }, PESEL::from_str_strict("4405140145a").unwrap_err());
+        assert_eq!(PeselError::InvalidDoB { year: 1944, month: 5, day: 32 }, PESEL::from_str_strict("44053201458").unwrap_err());
+    }
+
+    #[test]
+    fn lenient_validator_should_accept_invalid_checksum() {
+        let pesel = PeselValidator::new().checksum_policy(ChecksumPolicy::Lenient).validate("44051401459").unwrap();
+
+        assert!(!pesel.is_valid());
+    }
+
+    #[test]
+    fn strict_validator_should_accept_known_exceptions() {
+        let registry = PeselExceptionRegistry::from_numbers(vec!["44051401459"]).unwrap();
+        let validator = PeselValidator::new().exceptions(registry);
+
+        let pesel = validator.validate("44051401459").unwrap();
+        assert_eq!(PeselValidity::RecognizedException, validator.validity(&pesel));
+        assert_eq!(PeselError::InvalidChecksum { expected: 8, found: 7 }, validator.validate("44051401457").unwrap_err());
+    }
+}